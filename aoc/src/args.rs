@@ -0,0 +1,353 @@
+use std::{path::PathBuf, str::FromStr};
+
+use common::{Day, InputSource, Level, Part, DEFAULT_YEAR};
+
+use crate::output::Format;
+
+pub const USAGE: &str = "\
+Usage: aoc [year] <day|all> [options]
+       aoc bench [year] <day|all> [--runs <n>] [--output <file>] [options]
+       aoc verify [year] [day|all] [--ledger <file>] [--record] [--allow-missing] [options]
+       aoc new [year] <day>
+       aoc watch [year] <day> [--stream] [options]
+       aoc fetch [year] <day|all> [--input-dir <dir>]
+       aoc submit [year] <day> <part> [--ledger <file>] [options]
+       aoc generate [year] <day> [--seed <n>] [--size <n>] [--output <file>] [--ledger <file>]
+       aoc repl [year] <day> [options]
+       aoc anonymise [year] <day> [--seed <n>] [--output <file>] [--ledger <file>] [options]
+
+Days are those of 2023 unless a year is given. The days of other years live
+in a directory named after the year, along with their answers.txt, and so do
+their inputs in an input directory.
+
+Options:
+  --part <1|2>       Only run one part
+  --input <path|->   Read the input from a file, or from stdin for -
+  --input-dir <dir>  Read inputs named day-NN.txt from a directory
+
+Run options:
+  --format <format>  Print answers as text (the default), json or tsv
+  --stream           Read the input one line at a time, for days which can
+  --explain [level]  Trace how the day reaches its answers on stderr, up to
+                     info, debug (the default) or trace
+
+Bench times parsing and each part over several runs, and writes the results
+as CSV. Built with --features alloc-stats, it also reports how many
+allocations each phase makes, how many bytes they ask for, and the most bytes
+held at once.
+
+Verify options:
+  --ledger <file>    Check against this answers file instead of the year's
+                     answers.txt
+  --record           Accept answers which the ledger does not know yet
+  --allow-missing    Succeed even if some parts give no answer yet
+
+Fetch downloads inputs which are not on disk yet, into --input-dir or
+AOC_INPUT_DIR if set. It needs AOC_SESSION to hold the session cookie of your
+account, and AOC_BASE_URL can point it at another server.
+
+Submit posts the answer of one part with the same session, and records what
+the server says of it in the ledger, the year's answers.txt unless --ledger is
+given. It refuses answers which the ledger already rules out, and holds back
+answers for as long as the server asks after a wrong one.
+
+Repl parses the input of a day, then runs commands against it as they are
+typed, such as which games show more than 15 red. Type help to list the
+commands of the day.
+
+Generate options:
+  --seed <n>         Seed the generator, 0 by default
+  --size <n>         Roughly how many lines to generate, 1000 by default
+  --output <file>    Write the input to a file instead of stdout
+  --ledger <file>    Record the expected answers in this answers file
+
+Anonymise rewrites the input of a day into another of the same shape, such as
+with its node names permuted, so that it can be shared as a fixture in its
+place. It reports the answers of the new input, and takes --seed, --output and
+--ledger like generate.";
+
+pub const DEFAULT_BENCH_RUNS: usize = 10;
+
+pub const DEFAULT_BENCH_OUTPUT: &str = "bench-results.csv";
+
+pub const DEFAULT_GENERATE_SIZE: usize = 1000;
+
+/// The first year of the event.
+const FIRST_YEAR: u16 = 2015;
+
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Selection::All),
+            _ => s
+                .parse::<u8>()
+                .map(Selection::Day)
+                .map_err(|_| format!("Invalid day {}", s)),
+        }
+    }
+}
+
+pub enum Command {
+    Run,
+    Bench {
+        runs: usize,
+        output: PathBuf,
+    },
+    Verify {
+        /// The year's ledger unless given.
+        ledger: Option<PathBuf>,
+        record: bool,
+        /// Parts without an answer do not count as failures.
+        allow_missing: bool,
+    },
+    /// Scaffolds the crate of a day which has no solution yet.
+    New,
+    /// Re-runs a day whenever its source or input changes.
+    Watch,
+    /// Downloads the inputs of days which have none yet.
+    Fetch,
+    /// Posts the answer of one part to the puzzle server.
+    Submit {
+        /// The year's ledger unless given.
+        ledger: Option<PathBuf>,
+    },
+    Generate {
+        seed: u64,
+        size: usize,
+        output: Option<PathBuf>,
+        ledger: Option<PathBuf>,
+    },
+    /// Runs commands typed on stdin against a day's parsed input.
+    Repl,
+    /// Rewrites a day's input into one which can be shared in its place.
+    Anonymise {
+        seed: u64,
+        output: Option<PathBuf>,
+        ledger: Option<PathBuf>,
+    },
+}
+
+pub struct Args {
+    pub command: Command,
+    pub year: u16,
+    pub selection: Selection,
+    pub parts: Vec<Part>,
+    pub input: Option<String>,
+    pub input_dir: Option<PathBuf>,
+    pub format: Format,
+    pub stream: bool,
+    /// The most detailed trace lines to print.
+    pub explain: Option<Level>,
+}
+
+fn value_of(flag: &str, args: &mut impl Iterator<Item = String>) -> Result<String, String> {
+    args.next()
+        .ok_or_else(|| format!("Missing value for {}", flag))
+}
+
+impl Args {
+    pub fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.peekable();
+        let mut command = match args.next_if(|arg| {
+            [
+                "bench",
+                "verify",
+                "new",
+                "watch",
+                "fetch",
+                "submit",
+                "generate",
+                "repl",
+                "anonymise",
+            ]
+            .contains(&arg.as_str())
+        }) {
+            Some(arg) if arg == "bench" => Command::Bench {
+                runs: DEFAULT_BENCH_RUNS,
+                output: PathBuf::from(DEFAULT_BENCH_OUTPUT),
+            },
+            Some(arg) if arg == "verify" => Command::Verify {
+                ledger: None,
+                record: false,
+                allow_missing: false,
+            },
+            Some(arg) if arg == "new" => Command::New,
+            Some(arg) if arg == "watch" => Command::Watch,
+            Some(arg) if arg == "fetch" => Command::Fetch,
+            Some(arg) if arg == "submit" => Command::Submit { ledger: None },
+            Some(arg) if arg == "repl" => Command::Repl,
+            Some(arg) if arg == "anonymise" => Command::Anonymise {
+                seed: 0,
+                output: None,
+                ledger: None,
+            },
+            Some(_) => Command::Generate {
+                seed: 0,
+                size: DEFAULT_GENERATE_SIZE,
+                output: None,
+                ledger: None,
+            },
+            None => Command::Run,
+        };
+        // Days only go up to 25, so four digits can only be a year
+        let year =
+            match args.next_if(|arg| arg.len() == 4 && arg.bytes().all(|b| b.is_ascii_digit())) {
+                Some(arg) => arg
+                    .parse::<u16>()
+                    .ok()
+                    .filter(|&year| year >= FIRST_YEAR)
+                    .ok_or_else(|| format!("Invalid year {}", arg))?,
+                None => DEFAULT_YEAR,
+            };
+        let selection = match (args.next_if(|arg| !arg.starts_with("--")), &command) {
+            (Some(arg), _) => arg.parse::<Selection>()?,
+            (None, Command::Verify { .. }) => Selection::All,
+            (None, _) => return Err(String::from("Missing day")),
+        };
+        let mut parts = Part::ALL.to_vec();
+        if let Command::Submit { .. } = command {
+            let part = args
+                .next_if(|arg| !arg.starts_with("--"))
+                .ok_or_else(|| String::from("submit needs a part"))?;
+            parts = vec![part.parse::<Part>()?];
+        }
+        let mut input = None;
+        let mut input_dir = None;
+        let mut format = Format::Text;
+        let mut stream = false;
+        let mut explain = None;
+        while let Some(arg) = args.next() {
+            match (arg.as_str(), &mut command) {
+                ("--part", Command::Submit { .. }) => {
+                    return Err(String::from("submit takes its part after the day"))
+                }
+                ("--part", _) => parts = vec![value_of(&arg, &mut args)?.parse::<Part>()?],
+                ("--input", _) => input = Some(value_of(&arg, &mut args)?),
+                ("--input-dir", _) => input_dir = Some(PathBuf::from(value_of(&arg, &mut args)?)),
+                ("--format", Command::Run) => format = value_of(&arg, &mut args)?.parse()?,
+                ("--stream", Command::Run | Command::Watch) => stream = true,
+                ("--explain", Command::Run) => {
+                    let level = args.next_if(|arg| arg.parse::<Level>().is_ok());
+                    explain = Some(level.map_or(Level::Debug, |level| level.parse().unwrap()));
+                }
+                ("--runs", Command::Bench { runs, .. }) => {
+                    *runs = value_of(&arg, &mut args)?
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|&runs| runs > 0)
+                        .ok_or_else(|| String::from("--runs needs a positive number"))?;
+                }
+                ("--output", Command::Bench { output, .. }) => {
+                    *output = PathBuf::from(value_of(&arg, &mut args)?);
+                }
+                ("--ledger", Command::Verify { ledger, .. }) => {
+                    *ledger = Some(PathBuf::from(value_of(&arg, &mut args)?));
+                }
+                ("--record", Command::Verify { record, .. }) => *record = true,
+                ("--allow-missing", Command::Verify { allow_missing, .. }) => *allow_missing = true,
+                ("--ledger", Command::Submit { ledger }) => {
+                    *ledger = Some(PathBuf::from(value_of(&arg, &mut args)?));
+                }
+                ("--seed", Command::Generate { seed, .. }) => {
+                    *seed = value_of(&arg, &mut args)?
+                        .parse::<u64>()
+                        .map_err(|_| String::from("--seed needs a number"))?;
+                }
+                ("--size", Command::Generate { size, .. }) => {
+                    *size = value_of(&arg, &mut args)?
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|&size| size > 0)
+                        .ok_or_else(|| String::from("--size needs a positive number"))?;
+                }
+                ("--output", Command::Generate { output, .. }) => {
+                    *output = Some(PathBuf::from(value_of(&arg, &mut args)?));
+                }
+                ("--ledger", Command::Generate { ledger, .. }) => {
+                    *ledger = Some(PathBuf::from(value_of(&arg, &mut args)?));
+                }
+                ("--seed", Command::Anonymise { seed, .. }) => {
+                    *seed = value_of(&arg, &mut args)?
+                        .parse::<u64>()
+                        .map_err(|_| String::from("--seed needs a number"))?;
+                }
+                ("--output", Command::Anonymise { output, .. }) => {
+                    *output = Some(PathBuf::from(value_of(&arg, &mut args)?));
+                }
+                ("--ledger", Command::Anonymise { ledger, .. }) => {
+                    *ledger = Some(PathBuf::from(value_of(&arg, &mut args)?));
+                }
+                _ => return Err(format!("Unexpected argument {}", arg)),
+            }
+        }
+        if matches!((&command, &selection), (Command::New, Selection::All)) {
+            return Err(String::from("new needs a single day"));
+        }
+        if matches!(
+            (&command, &selection),
+            (Command::Generate { .. }, Selection::All)
+        ) {
+            return Err(String::from("generate needs a single day"));
+        }
+        if matches!(
+            (&command, &selection),
+            (Command::Submit { .. }, Selection::All)
+        ) {
+            return Err(String::from("submit needs a single day"));
+        }
+        if matches!(
+            (&command, &selection),
+            (Command::Anonymise { .. }, Selection::All)
+        ) {
+            return Err(String::from("anonymise needs a single day"));
+        }
+        if matches!((&command, &selection), (Command::Repl, Selection::All)) {
+            return Err(String::from("repl needs a single day"));
+        }
+        if matches!(command, Command::Repl) && input.as_deref() == Some("-") {
+            return Err(String::from("repl reads its commands from stdin"));
+        }
+        if matches!((&command, &selection), (Command::Watch, Selection::All)) {
+            return Err(String::from("watch needs a single day"));
+        }
+        if matches!(command, Command::Watch) && input.as_deref() == Some("-") {
+            return Err(String::from("watch cannot read its input from stdin"));
+        }
+        if input.is_some() && matches!(selection, Selection::All) {
+            return Err(String::from("--input needs a single day"));
+        }
+        if stream && matches!(selection, Selection::All) {
+            return Err(String::from("--stream needs a single day"));
+        }
+        if explain.is_some() && matches!(selection, Selection::All) {
+            return Err(String::from("--explain needs a single day"));
+        }
+        Ok(Args {
+            command,
+            year,
+            selection,
+            parts,
+            input,
+            input_dir,
+            format,
+            stream,
+            explain,
+        })
+    }
+
+    pub fn input_source(&self, day: &Day) -> InputSource {
+        match &self.input_dir {
+            Some(dir) => {
+                InputSource::resolve(day.year, day.number, self.input.as_deref(), Some(dir))
+            }
+            None => InputSource::for_day(day.year, day.number, self.input.as_deref()),
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use std::{fmt, fs, io, path::Path, time::Duration};
+
+use common::{AllocStats, Day, Part};
+
+use crate::args::Args;
+
+#[derive(Clone, Copy)]
+enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+/// How long one phase of a day took over every run.
+struct PhaseTimings {
+    year: u16,
+    day: u8,
+    phase: Phase,
+    times: Vec<Duration>,
+    /// What the last run allocated, when allocations are counted. Every run
+    /// allocates the same.
+    allocations: Option<AllocStats>,
+}
+
+struct Stats {
+    min: Duration,
+    median: Duration,
+    max: Duration,
+}
+
+impl PhaseTimings {
+    fn stats(&self) -> Stats {
+        let mut times = self.times.clone();
+        times.sort();
+        let middle = times.len() / 2;
+        let median = if times.len().is_multiple_of(2) {
+            (times[middle - 1] + times[middle]) / 2
+        } else {
+            times[middle]
+        };
+        Stats {
+            min: times[0],
+            median,
+            max: times[times.len() - 1],
+        }
+    }
+}
+
+fn bench_day(day: &Day, args: &Args, runs: usize) -> common::Result<Vec<PhaseTimings>> {
+    let input = args.input_source(day).read()?;
+    let mut parse_times = vec![];
+    let mut parse_allocations = None;
+    let mut part_times = vec![vec![]; args.parts.len()];
+    let mut part_allocations = vec![None; args.parts.len()];
+    for _ in 0..runs {
+        let outcome = day.run(&input, &args.parts)?;
+        parse_times.push(outcome.parse_time);
+        parse_allocations = outcome.parse_allocations;
+        for ((part, times), allocations) in outcome
+            .parts
+            .into_iter()
+            .zip(part_times.iter_mut())
+            .zip(part_allocations.iter_mut())
+        {
+            // Unsolved parts return straight away, so timing them says nothing
+            if part.answer?.is_some() {
+                times.push(part.time);
+                *allocations = part.allocations;
+            }
+        }
+    }
+    let mut timings = vec![PhaseTimings {
+        year: day.year,
+        day: day.number,
+        phase: Phase::Parse,
+        times: parse_times,
+        allocations: parse_allocations,
+    }];
+    for ((&part, times), allocations) in args
+        .parts
+        .iter()
+        .zip(part_times)
+        .zip(part_allocations)
+    {
+        if !times.is_empty() {
+            timings.push(PhaseTimings {
+                year: day.year,
+                day: day.number,
+                phase: Phase::Solve(part),
+                times,
+                allocations,
+            });
+        }
+    }
+    Ok(timings)
+}
+
+/// Whether allocations were counted, which takes building common with the
+/// `alloc-stats` feature.
+fn counted_allocations(timings: &[PhaseTimings]) -> bool {
+    timings
+        .iter()
+        .any(|timing| timing.allocations.is_some())
+}
+
+/// Bytes in binary units, such as `1.5 MiB`.
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut amount = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while amount >= 1024.0 && unit + 1 < UNITS.len() {
+        amount /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", amount, UNITS[unit])
+}
+
+fn print_table(timings: &[PhaseTimings]) {
+    let allocations = counted_allocations(timings);
+    let mut header = format!(
+        "{:>3}  {:<6}  {:>5}  {:>12}  {:>12}  {:>12}",
+        "Day", "Phase", "Runs", "Min", "Median", "Max"
+    );
+    if allocations {
+        header += &format!(
+            "  {:>12}  {:>10}  {:>10}",
+            "Allocations", "Allocated", "Peak"
+        );
+    }
+    println!("{}", header);
+    for timing in timings {
+        let stats = timing.stats();
+        let mut row = format!(
+            "{:>3}  {:<6}  {:>5}  {:>12}  {:>12}  {:>12}",
+            timing.day,
+            timing.phase.to_string(),
+            timing.times.len(),
+            format!("{:?}", stats.min),
+            format!("{:?}", stats.median),
+            format!("{:?}", stats.max),
+        );
+        if let Some(counts) = timing.allocations {
+            row += &format!(
+                "  {:>12}  {:>10}  {:>10}",
+                counts.allocations,
+                format_bytes(counts.allocated_bytes),
+                format_bytes(counts.peak_bytes)
+            );
+        }
+        println!("{}", row);
+    }
+}
+
+/// The results as CSV, a row per phase of each day.
+fn results_csv(timings: &[PhaseTimings]) -> String {
+    let allocations = counted_allocations(timings);
+    let mut csv = String::from("year,day,phase,runs,min_ns,median_ns,max_ns");
+    if allocations {
+        csv += ",allocations,allocated_bytes,peak_bytes";
+    }
+    csv.push('\n');
+    for timing in timings {
+        let stats = timing.stats();
+        csv += &format!(
+            "{},{},{},{},{},{},{}",
+            timing.year,
+            timing.day,
+            timing.phase,
+            timing.times.len(),
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.max.as_nanos(),
+        );
+        if let Some(counts) = timing.allocations {
+            csv += &format!(
+                ",{},{},{}",
+                counts.allocations, counts.allocated_bytes, counts.peak_bytes
+            );
+        }
+        csv.push('\n');
+    }
+    csv
+}
+
+fn write_results(timings: &[PhaseTimings], output: &Path) -> io::Result<()> {
+    fs::write(output, results_csv(timings))
+}
+
+/// Times parsing and each part of `days` over `runs` runs, prints a summary
+/// and writes the results to `output` as CSV. Returns whether every day could
+/// be timed.
+pub fn bench(days: &[Day], args: &Args, runs: usize, output: &Path) -> bool {
+    let mut succeeded = true;
+    let mut timings = vec![];
+    for day in days {
+        match bench_day(day, args, runs) {
+            Ok(day_timings) => timings.extend(day_timings),
+            Err(error) => {
+                eprintln!("Day {}: {}", day.number, error);
+                succeeded = false;
+            }
+        }
+    }
+    print_table(&timings);
+    if let Err(error) = write_results(&timings, output) {
+        eprintln!("Cannot write {}: {}", output.display(), error);
+        succeeded = false;
+    }
+    succeeded
+}
+
+#[cfg(test)]
+mod tests {
+    use common::DEFAULT_YEAR;
+
+    use super::*;
+
+    fn timing(year: u16, phase: Phase, allocations: Option<AllocStats>) -> PhaseTimings {
+        PhaseTimings {
+            year,
+            day: 5,
+            phase,
+            times: vec![
+                Duration::from_nanos(30),
+                Duration::from_nanos(10),
+                Duration::from_nanos(20),
+            ],
+            allocations,
+        }
+    }
+
+    #[test]
+    fn results_start_with_the_year() {
+        let timings = [
+            timing(2022, Phase::Parse, None),
+            timing(DEFAULT_YEAR, Phase::Solve(Part::Two), None),
+        ];
+        assert_eq!(
+            results_csv(&timings),
+            "year,day,phase,runs,min_ns,median_ns,max_ns\n\
+             2022,5,parse,3,10,20,30\n\
+             2023,5,part2,3,10,20,30\n"
+        );
+    }
+
+    #[test]
+    fn results_count_allocations_when_counted() {
+        let allocations = AllocStats {
+            allocations: 1,
+            allocated_bytes: 2,
+            peak_bytes: 3,
+        };
+        assert_eq!(
+            results_csv(&[timing(DEFAULT_YEAR, Phase::Parse, Some(allocations))]),
+            "year,day,phase,runs,min_ns,median_ns,max_ns,allocations,allocated_bytes,peak_bytes\n\
+             2023,5,parse,3,10,20,30,1,2,3\n"
+        );
+    }
+}
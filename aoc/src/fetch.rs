@@ -0,0 +1,31 @@
+use std::{env, path::PathBuf};
+
+use common::{Day, Fetched, Fetcher, INPUT_DIR_VAR};
+
+use crate::args::Args;
+
+/// Downloads the inputs of `days` which are not on disk yet, printing where
+/// each one is. Returns whether every input is now on disk.
+pub fn fetch(days: &[Day], args: &Args) -> bool {
+    let input_dir = args
+        .input_dir
+        .clone()
+        .or_else(|| env::var_os(INPUT_DIR_VAR).map(PathBuf::from));
+    let mut fetcher = Fetcher::from_env(input_dir.as_deref());
+    let mut succeeded = true;
+    for day in days {
+        match fetcher.fetch(day.year, day.number) {
+            Ok(Fetched::Cached(path)) => {
+                println!("Day {}: already in {}", day.number, path.display())
+            }
+            Ok(Fetched::Downloaded(path)) => {
+                println!("Day {}: downloaded to {}", day.number, path.display())
+            }
+            Err(error) => {
+                eprintln!("Day {}: {}", day.number, error);
+                succeeded = false;
+            }
+        }
+    }
+    succeeded
+}
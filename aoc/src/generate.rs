@@ -0,0 +1,108 @@
+use std::{fs, path::Path};
+
+use common::{Day, Generated, InputId, Ledger, Part, Rng};
+
+use crate::args::Args;
+
+/// Writes a generated input for `day` to `output`, or to stdout, and reports
+/// the expected answers on stderr. With `ledger`, the expected answers are
+/// also recorded there against the input. Returns whether all of it worked.
+pub fn generate(
+    day: &Day,
+    seed: u64,
+    size: usize,
+    output: Option<&Path>,
+    ledger: Option<&Path>,
+) -> bool {
+    match day.generate(&mut Rng::new(seed), size) {
+        Some(generated) => deliver(day, &generated, output, ledger),
+        None => {
+            eprintln!("Day {} cannot generate inputs", day.number);
+            false
+        }
+    }
+}
+
+/// Rewrites the input of `day` into one of the same shape, then writes and
+/// reports it like [`generate`].
+pub fn anonymise(
+    day: &Day,
+    args: &Args,
+    seed: u64,
+    output: Option<&Path>,
+    ledger: Option<&Path>,
+) -> bool {
+    let input = match args.input_source(day).read() {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Day {}: {}", day.number, error);
+            return false;
+        }
+    };
+    match day.anonymise(&input, &mut Rng::new(seed)) {
+        Some(Ok(generated)) => deliver(day, &generated, output, ledger),
+        Some(Err(error)) => {
+            eprintln!("Day {}: {}", day.number, error);
+            false
+        }
+        None => {
+            eprintln!("Day {} cannot anonymise inputs", day.number);
+            false
+        }
+    }
+}
+
+fn deliver(day: &Day, generated: &Generated, output: Option<&Path>, ledger: Option<&Path>) -> bool {
+    match output {
+        Some(path) => {
+            if let Err(error) = fs::write(path, &generated.input) {
+                eprintln!("Cannot write {}: {}", path.display(), error);
+                return false;
+            }
+        }
+        None => print!("{}", generated.input),
+    }
+    for (part, answer) in Part::ALL.iter().zip(&generated.answers) {
+        match answer {
+            Some(answer) => eprintln!("Day {}, part {}: expecting {}", day.number, part, answer),
+            None => eprintln!("Day {}, part {}: no expected answer", day.number, part),
+        }
+    }
+    match ledger {
+        Some(path) => record(day.number, &generated.input, &generated.answers, path),
+        None => true,
+    }
+}
+
+fn record(day: u8, input: &str, answers: &[Option<String>; 2], path: &Path) -> bool {
+    let mut ledger = match Ledger::load(path) {
+        Ok(ledger) => ledger,
+        Err(error) => {
+            eprintln!("Ledger {}: {}", path.display(), error);
+            return false;
+        }
+    };
+    let input_id = InputId::of(input);
+    for (&part, answer) in Part::ALL.iter().zip(answers) {
+        let Some(answer) = answer else {
+            continue;
+        };
+        if !ledger.record(day, part, input_id, answer) {
+            if let Some(accepted) = ledger.answer(day, part, input_id) {
+                if accepted != answer {
+                    eprintln!(
+                        "Day {}, part {}: the ledger already accepted {}",
+                        day, part, accepted
+                    );
+                }
+            }
+        }
+    }
+    match ledger.save() {
+        Ok(()) => true,
+        Err(error) => {
+            eprintln!("Cannot write {}: {}", path.display(), error);
+            false
+        }
+    }
+}
@@ -0,0 +1,143 @@
+mod args;
+mod bench;
+mod fetch;
+mod generate;
+mod output;
+mod parallel;
+mod repl;
+mod scaffold;
+mod submit;
+mod verify;
+mod watch;
+
+use std::{env, process, process::ExitCode};
+
+use args::{Args, Command, Selection, USAGE};
+use common::{default_ledger_path, Day};
+
+/// Prints the answers of one day, returning whether all of them succeeded.
+fn run_day(day: &Day, args: &Args) -> bool {
+    if args.stream {
+        return stream_day(day, args);
+    }
+    let outcome = args
+        .input_source(day)
+        .read()
+        .and_then(|input| day.run(&input, &args.parts));
+    match outcome {
+        Ok(outcome) => {
+            args.format
+                .print_outcome(day.year, day.number, &outcome);
+            outcome
+                .parts
+                .iter()
+                .all(|part| part.answer.is_ok())
+        }
+        Err(error) => {
+            args.format
+                .print_day_error(day.year, day.number, &args.parts, &error);
+            false
+        }
+    }
+}
+
+fn stream_day(day: &Day, args: &Args) -> bool {
+    let source = args.input_source(day);
+    match day.stream(&source, &args.parts) {
+        Some(Ok(outcome)) => {
+            args.format
+                .print_stream_outcome(day.year, day.number, &outcome);
+            true
+        }
+        Some(Err(error)) => {
+            args.format
+                .print_day_error(day.year, day.number, &args.parts, &error);
+            false
+        }
+        None => {
+            eprintln!("Day {} cannot stream its input", day.number);
+            false
+        }
+    }
+}
+
+fn selected_days(year: u16, selection: &Selection) -> Result<Vec<Day>, String> {
+    match selection {
+        Selection::All => Some(registry::days_of(year))
+            .filter(|days| !days.is_empty())
+            .ok_or_else(|| format!("No solutions for {}", year)),
+        Selection::Day(number) => registry::find(year, *number)
+            .map(|day| vec![day])
+            .ok_or_else(|| format!("No solution for day {} of {}", number, year)),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse(env::args().skip(1)).unwrap_or_else(|message| {
+        eprintln!("{}\n{}", message, USAGE);
+        process::exit(2);
+    });
+    if let (Command::New, Selection::Day(number)) = (&args.command, &args.selection) {
+        return match scaffold::new_day(&scaffold::workspace_root(), args.year, *number) {
+            Ok(dir) => {
+                println!("Created {}, rebuild to run it", dir.display());
+                ExitCode::SUCCESS
+            }
+            Err(message) => {
+                eprintln!("{}", message);
+                ExitCode::FAILURE
+            }
+        };
+    }
+    let days = selected_days(args.year, &args.selection).unwrap_or_else(|message| {
+        eprintln!("{}", message);
+        process::exit(2);
+    });
+    common::set_trace_level(args.explain);
+    let succeeded = match &args.command {
+        Command::Run => match args.selection {
+            Selection::All => parallel::run_all(&days, &args),
+            Selection::Day(_) => {
+                args.format.print_header();
+                run_day(&days[0], &args)
+            }
+        },
+        Command::Bench { runs, output } => bench::bench(&days, &args, *runs, output),
+        Command::Verify {
+            ledger,
+            record,
+            allow_missing,
+        } => {
+            let ledger = ledger
+                .clone()
+                .unwrap_or_else(|| default_ledger_path(args.year));
+            verify::verify(&days, &args, &ledger, *record, *allow_missing)
+        }
+        Command::Generate {
+            seed,
+            size,
+            output,
+            ledger,
+        } => generate::generate(&days[0], *seed, *size, output.as_deref(), ledger.as_deref()),
+        Command::Watch => watch::watch(&days[0], &args),
+        Command::Fetch => fetch::fetch(&days, &args),
+        Command::Submit { ledger } => {
+            let ledger = ledger
+                .clone()
+                .unwrap_or_else(|| default_ledger_path(args.year));
+            submit::submit(&days[0], args.parts[0], &args, &ledger)
+        }
+        Command::Repl => repl::repl(&days[0], &args),
+        Command::Anonymise {
+            seed,
+            output,
+            ledger,
+        } => generate::anonymise(&days[0], &args, *seed, output.as_deref(), ledger.as_deref()),
+        Command::New => unreachable!("new is handled before selecting days"),
+    };
+    if succeeded {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
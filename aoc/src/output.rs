@@ -0,0 +1,256 @@
+use std::{fmt, str::FromStr, time::Duration};
+
+use common::{Error, Outcome, Part, StreamOutcome};
+
+/// How the runner prints answers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// One sentence per part, errors going to stderr.
+    Text,
+    /// One JSON object per part and line.
+    Json,
+    /// Tab separated values with a header row.
+    Tsv,
+}
+
+/// The header row of [`Format::Tsv`].
+pub const TSV_HEADER: &str = "year\tday\tpart\tstatus\tanswer\tparse_ns\ttime_ns";
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(format!("Invalid format {}, expected text, json or tsv", s)),
+        }
+    }
+}
+
+/// The result of one part, flattened for printing.
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: Result<Option<&'a str>, String>,
+    parse_time: Option<Duration>,
+    time: Option<Duration>,
+}
+
+impl Record<'_> {
+    fn status(&self) -> &'static str {
+        match self.answer {
+            Ok(Some(_)) => "solved",
+            Ok(None) => "unsolved",
+            Err(_) => "error",
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            '\r' => json += "\\r",
+            '\t' => json += "\\t",
+            c if c.is_control() => json += &format!("\\u{:04x}", c as u32),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn json_nanos(time: Option<Duration>) -> String {
+    time.map_or_else(|| String::from("null"), |time| time.as_nanos().to_string())
+}
+
+fn tsv_field(s: &str) -> String {
+    s.replace(['\t', '\n', '\r'], " ")
+}
+
+fn tsv_nanos(time: Option<Duration>) -> String {
+    time.map_or_else(String::new, |time| time.as_nanos().to_string())
+}
+
+fn json_row(record: &Record) -> String {
+    let (answer, error) = match &record.answer {
+        Ok(answer) => (
+            answer.map_or_else(|| String::from("null"), json_string),
+            None,
+        ),
+        Err(error) => (String::from("null"), Some(error)),
+    };
+    let error = error.map_or_else(String::new, |error| {
+        format!(",\"error\":{}", json_string(error))
+    });
+    format!(
+        "{{\"year\":{},\"day\":{},\"part\":{},\"status\":\"{}\",\"answer\":{}{},\"parse_ns\":{},\"time_ns\":{}}}",
+        record.year,
+        record.day,
+        record.part,
+        record.status(),
+        answer,
+        error,
+        json_nanos(record.parse_time),
+        json_nanos(record.time),
+    )
+}
+
+fn tsv_row(record: &Record) -> String {
+    let answer = match &record.answer {
+        Ok(answer) => tsv_field(answer.unwrap_or_default()),
+        Err(error) => tsv_field(error),
+    };
+    format!(
+        "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+        record.year,
+        record.day,
+        record.part,
+        record.status(),
+        answer,
+        tsv_nanos(record.parse_time),
+        tsv_nanos(record.time),
+    )
+}
+
+impl Format {
+    /// Printed once, before any answer.
+    pub fn print_header(&self) {
+        if *self == Format::Tsv {
+            println!("{}", TSV_HEADER);
+        }
+    }
+
+    fn print(&self, record: &Record) {
+        match self {
+            Format::Text => match &record.answer {
+                Ok(Some(answer)) => {
+                    println!("Day {}, part {}: {}", record.day, record.part, answer)
+                }
+                Ok(None) => println!("Day {}, part {}: unsolved", record.day, record.part),
+                Err(error) => eprintln!("Day {}, part {}: {}", record.day, record.part, error),
+            },
+            Format::Json => println!("{}", json_row(record)),
+            Format::Tsv => println!("{}", tsv_row(record)),
+        }
+    }
+
+    /// Prints every part of a day which ran.
+    pub fn print_outcome(&self, year: u16, day: u8, outcome: &Outcome) {
+        for part in &outcome.parts {
+            self.print(&Record {
+                year,
+                day,
+                part: part.part,
+                answer: part
+                    .answer
+                    .as_ref()
+                    .map(Option::as_deref)
+                    .map_err(Error::to_string),
+                parse_time: Some(outcome.parse_time),
+                time: Some(part.time),
+            });
+        }
+    }
+
+    /// Prints every part of a day which streamed its input. The parts share
+    /// one pass, so each is given the time of the whole pass.
+    pub fn print_stream_outcome(&self, year: u16, day: u8, outcome: &StreamOutcome) {
+        for (part, answer) in &outcome.answers {
+            self.print(&Record {
+                year,
+                day,
+                part: *part,
+                answer: Ok(answer.as_deref()),
+                parse_time: None,
+                time: Some(outcome.time),
+            });
+        }
+    }
+
+    /// Prints a day whose input could not be read or parsed. Text reports it
+    /// once, the structured formats once for each part that was asked for.
+    pub fn print_day_error(&self, year: u16, day: u8, parts: &[Part], error: &impl fmt::Display) {
+        if *self == Format::Text {
+            eprintln!("Day {}: {}", day, error);
+            return;
+        }
+        for &part in parts {
+            self.print(&Record {
+                year,
+                day,
+                part,
+                answer: Err(error.to_string()),
+                parse_time: None,
+                time: None,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(answer: Result<Option<&str>, String>) -> Record<'_> {
+        Record {
+            year: 2023,
+            day: 5,
+            part: Part::Two,
+            answer,
+            parse_time: Some(Duration::from_nanos(1500)),
+            time: None,
+        }
+    }
+
+    #[test]
+    fn json_strings_are_escaped() {
+        assert_eq!(json_string("plain"), "\"plain\"");
+        assert_eq!(json_string("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(json_string("a\\b"), "\"a\\\\b\"");
+        assert_eq!(json_string("1\n2\r\t3"), "\"1\\n2\\r\\t3\"");
+        assert_eq!(json_string("\u{1}\u{7f}"), "\"\\u0001\\u007f\"");
+        assert_eq!(json_string("é"), "\"é\"");
+    }
+
+    #[test]
+    fn json_rows_hold_every_field() {
+        assert_eq!(
+            json_row(&record(Ok(Some("46")))),
+            r#"{"year":2023,"day":5,"part":2,"status":"solved","answer":"46","parse_ns":1500,"time_ns":null}"#
+        );
+        assert_eq!(
+            json_row(&record(Ok(None))),
+            r#"{"year":2023,"day":5,"part":2,"status":"unsolved","answer":null,"parse_ns":1500,"time_ns":null}"#
+        );
+        assert_eq!(
+            json_row(&record(Err(String::from("No \"seeds\"")))),
+            r#"{"year":2023,"day":5,"part":2,"status":"error","answer":null,"error":"No \"seeds\"","parse_ns":1500,"time_ns":null}"#
+        );
+    }
+
+    #[test]
+    fn tsv_rows_follow_the_header() {
+        assert_eq!(
+            TSV_HEADER.split('\t').count(),
+            tsv_row(&record(Ok(None)))
+                .split('\t')
+                .count()
+        );
+        assert_eq!(
+            tsv_row(&record(Ok(Some("46")))),
+            "2023\t5\t2\tsolved\t46\t1500\t"
+        );
+        assert_eq!(tsv_row(&record(Ok(None))), "2023\t5\t2\tunsolved\t\t1500\t");
+        assert_eq!(
+            tsv_row(&record(Err(String::from("bad\tline\nhere")))),
+            "2023\t5\t2\terror\tbad line here\t1500\t"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use std::{
+    any::Any,
+    fmt,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use common::{Day, Outcome};
+
+use crate::{args::Args, output::Format};
+
+/// Why a day gave no outcome at all.
+enum Failure {
+    Error(common::Error),
+    Panic(String),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Error(error) => write!(f, "{}", error),
+            Failure::Panic(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+struct DayReport {
+    year: u16,
+    day: u8,
+    result: Result<Outcome, Failure>,
+}
+
+/// What a caught panic said, for reporting it.
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => String::from(*message),
+            Err(_) => String::from("unknown cause"),
+        },
+    }
+}
+
+fn run_day(day: &Day, args: &Args) -> DayReport {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        args.input_source(day)
+            .read()
+            .and_then(|input| day.run(&input, &args.parts))
+    }));
+    DayReport {
+        year: day.year,
+        day: day.number,
+        result: match result {
+            Ok(Ok(outcome)) => Ok(outcome),
+            Ok(Err(error)) => Err(Failure::Error(error)),
+            Err(payload) => Err(Failure::Panic(panic_message(payload))),
+        },
+    }
+}
+
+/// Runs every day on a pool of threads, one per core. A day which fails or
+/// panics is reported as such without stopping the others.
+fn run_days(days: &[Day], args: &Args) -> Vec<DayReport> {
+    let workers = thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .min(days.len());
+    let next = AtomicUsize::new(0);
+    let reports = Mutex::new(vec![]);
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let report = run_day(day, args);
+                    reports.lock().unwrap().push(report);
+                }
+            });
+        }
+    });
+    let mut reports = reports.into_inner().unwrap();
+    reports.sort_by_key(|report| report.day);
+    reports
+}
+
+fn answer_cell(answer: &common::Result<Option<String>>) -> &str {
+    match answer {
+        Ok(Some(answer)) => answer,
+        Ok(None) => "unsolved",
+        Err(_) => "error",
+    }
+}
+
+fn print_table(reports: &[DayReport], args: &Args, wall_time: Duration) {
+    let mut header = format!("{:>3}", "Day");
+    for part in &args.parts {
+        header += &format!("  {:<16}", format!("Part {}", part));
+    }
+    header += &format!("  {:>10}", "Parse");
+    for part in &args.parts {
+        header += &format!("  {:>10}", format!("Part {}", part));
+    }
+    println!("{}", header.trim_end());
+
+    let (mut solved, mut unsolved, mut failed) = (0, 0, 0);
+    let mut problems = vec![];
+    for report in reports {
+        let mut row = format!("{:>3}", report.day);
+        match &report.result {
+            Ok(outcome) => {
+                for part in &outcome.parts {
+                    row += &format!("  {:<16}", answer_cell(&part.answer));
+                    match &part.answer {
+                        Ok(Some(_)) => solved += 1,
+                        Ok(None) => unsolved += 1,
+                        Err(error) => {
+                            failed += 1;
+                            problems
+                                .push(format!("Day {}, part {}: {}", report.day, part.part, error));
+                        }
+                    }
+                }
+                row += &format!("  {:>10}", format!("{:.2?}", outcome.parse_time));
+                for part in &outcome.parts {
+                    row += &format!("  {:>10}", format!("{:.2?}", part.time));
+                }
+            }
+            Err(failure) => {
+                let cell = match failure {
+                    Failure::Error(_) => "failed",
+                    Failure::Panic(_) => "panicked",
+                };
+                for _ in &args.parts {
+                    row += &format!("  {:<16}", cell);
+                }
+                failed += args.parts.len();
+                problems.push(format!("Day {}: {}", report.day, failure));
+            }
+        }
+        println!("{}", row.trim_end());
+    }
+    for problem in problems {
+        eprintln!("{}", problem);
+    }
+    println!(
+        "{} days in {:.2?}: {} solved, {} unsolved, {} failed",
+        reports.len(),
+        wall_time,
+        solved,
+        unsolved,
+        failed
+    );
+}
+
+/// Runs `days` in parallel and prints their answers once all have finished:
+/// as a summary table for text, or as records in day order otherwise.
+/// Returns whether every part succeeded.
+pub fn run_all(days: &[Day], args: &Args) -> bool {
+    let start = Instant::now();
+    let reports = run_days(days, args);
+    let wall_time = start.elapsed();
+    match args.format {
+        Format::Text => print_table(&reports, args, wall_time),
+        format => {
+            format.print_header();
+            for report in &reports {
+                match &report.result {
+                    Ok(outcome) => format.print_outcome(report.year, report.day, outcome),
+                    Err(failure) => {
+                        format.print_day_error(report.year, report.day, &args.parts, failure)
+                    }
+                }
+            }
+        }
+    }
+    reports
+        .iter()
+        .all(|report| match &report.result {
+            Ok(outcome) => outcome
+                .parts
+                .iter()
+                .all(|part| part.answer.is_ok()),
+            Err(_) => false,
+        })
+}
@@ -0,0 +1,138 @@
+use std::io::{self, BufRead, IsTerminal, Write};
+
+use common::{Day, Error, ReplCommand, Session};
+
+use crate::args::Args;
+
+/// The commands of every day, as `help` lists them.
+const BUILT_IN: [(&str, &str); 2] = [
+    ("help", "List the commands"),
+    ("quit", "Leave, as does the end of input"),
+];
+
+fn usage(command: &ReplCommand) -> String {
+    if command.args.is_empty() {
+        String::from(command.name)
+    } else {
+        format!("{} {}", command.name, command.args)
+    }
+}
+
+fn print_help(session: &Session) {
+    let commands = session
+        .commands
+        .iter()
+        .map(|command| (usage(command), command.help))
+        .chain(
+            BUILT_IN
+                .iter()
+                .map(|&(name, help)| (String::from(name), help)),
+        )
+        .collect::<Vec<_>>();
+    let width = commands
+        .iter()
+        .map(|(usage, _)| usage.len())
+        .max()
+        .unwrap_or(0);
+    for (usage, help) in commands {
+        println!("  {:<width$}  {}", usage, help);
+    }
+}
+
+/// Runs one line, returning whether it worked.
+fn run_line(day: &Day, session: &Session, line: &str) -> bool {
+    let Some(result) = session.run(line) else {
+        eprintln!(
+            "Day {} has no command {}, type help to list them",
+            day.number,
+            line.split_whitespace()
+                .next()
+                .unwrap_or_default()
+        );
+        return false;
+    };
+    match result {
+        Ok(output) if output.is_empty() => true,
+        Ok(output) => {
+            println!("{}", output);
+            true
+        }
+        // A line holds a single command, so only the column says anything
+        Err(Error::Parse(error)) => {
+            let command = line
+                .split_whitespace()
+                .next()
+                .and_then(|name| session.command(name));
+            match error.column {
+                Some(column) => eprintln!("Column {}: {}", column, error.message),
+                None => eprintln!("{}", error.message),
+            }
+            if let Some(command) = command {
+                eprintln!("Usage: {}", usage(command));
+            }
+            false
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            false
+        }
+    }
+}
+
+/// Parses the input of `day`, then runs the commands read from stdin
+/// against it until `quit` or the end of input. Returns whether every
+/// command worked.
+pub fn repl(day: &Day, args: &Args) -> bool {
+    let session = match args
+        .input_source(day)
+        .read()
+        .map(|input| day.explore(&input))
+    {
+        Ok(Some(Ok(session))) => session,
+        Ok(None) => {
+            eprintln!("Day {} has no commands to explore its input", day.number);
+            return false;
+        }
+        Ok(Some(Err(error))) | Err(error) => {
+            eprintln!("Day {}: {}", day.number, error);
+            return false;
+        }
+    };
+    // Prompts would only clutter the output of piped commands
+    let interactive = io::stdin().is_terminal();
+    if interactive {
+        eprintln!(
+            "Day {} of {} is parsed, type help to list its commands",
+            day.number, day.year
+        );
+    }
+    let mut succeeded = true;
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        if interactive {
+            print!("day {}> ", day.number);
+            // A prompt which does not show is no worse than no prompt
+            let _ = io::stdout().flush();
+        }
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            Some(Err(error)) => {
+                eprintln!("Cannot read stdin: {}", error);
+                return false;
+            }
+            None => {
+                // Leave the shell prompt on a line of its own
+                if interactive {
+                    println!();
+                }
+                break;
+            }
+        };
+        match line.trim() {
+            "help" => print_help(&session),
+            "quit" | "exit" => break,
+            _ => succeeded &= run_line(day, &session, &line),
+        }
+    }
+    succeeded
+}
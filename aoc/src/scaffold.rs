@@ -0,0 +1,256 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use common::{day_dir, DEFAULT_YEAR};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/day/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/day/lib.rs.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/day/main.rs.tmpl");
+
+/// Root of the workspace this runner was built in.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc should live inside the workspace")
+        .to_owned()
+}
+
+/// The package name of a day's crate. Days of the default year keep the
+/// plain `day-NN` of the crates which came before years; the others need the
+/// year in it to be unique in the workspace, after a letter as a library name
+/// cannot start with a digit.
+fn crate_name(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("day-{:02}", day)
+    } else {
+        format!("y{}-day-{:02}", year, day)
+    }
+}
+
+/// What the registry calls the solution of a day, which only needs
+/// the year to tell it apart from the default year's.
+fn solution_name(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("Day{:02}", day)
+    } else {
+        format!("Y{}Day{:02}", year, day)
+    }
+}
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    let depth = day_dir(year, day).components().count();
+    template
+        .replace("{{crate}}", &crate_name(year, day))
+        .replace("{{lib}}", &crate_name(year, day).replace('-', "_"))
+        .replace("{{solution}}", &solution_name(year, day))
+        .replace("{{common}}", &("../".repeat(depth) + "common"))
+        .replace("{{year}}", &year.to_string())
+        .replace("{{padded}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Adds `line` among the entries of `text`, the lines picked out by
+/// `is_entry`, keeping them sorted. An entry may go on over more indented
+/// lines. Returns `None` if `text` has no entries.
+fn add_entry(text: &str, line: &str, is_entry: impl Fn(&str) -> bool) -> Option<String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let entries = (0..lines.len())
+        .filter(|&i| is_entry(lines[i]))
+        .collect::<Vec<_>>();
+    let last = *entries.last()?;
+    let at = entries
+        .iter()
+        .copied()
+        .find(|&i| lines[i].trim_start() > line.trim_start())
+        .unwrap_or_else(|| {
+            let continued = lines[last + 1..]
+                .iter()
+                .take_while(|next| indentation(next) > indentation(lines[last]))
+                .count();
+            last + 1 + continued
+        });
+    lines.insert(at, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds `line` among the entries of the `key = [...]` array of a manifest,
+/// leaving entries of its other arrays alone.
+fn add_array_entry(
+    text: &str,
+    key: &str,
+    line: &str,
+    is_entry: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let start = text.find(&format!("\n{} = [\n", key))? + 1;
+    let end = start + text[start..].find("\n]")? + 1;
+    let array = add_entry(&text[start..end], line, is_entry)?;
+    Some(format!("{}{}{}", &text[..start], array, &text[end..]))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("Cannot read {}: {}", path.display(), error))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|error| format!("Cannot write {}: {}", path.display(), error))
+}
+
+/// Edits a file of the workspace to mention the new day.
+fn register(path: &Path, line: &str, is_entry: impl Fn(&str) -> bool) -> Result<String, String> {
+    add_entry(&read(path)?, line, is_entry).ok_or_else(|| {
+        format!(
+            "Cannot find where to register the day in {}",
+            path.display()
+        )
+    })
+}
+
+/// Creates the crate of `day` of `year` under `root` from the template, in
+/// the year's directory, and adds it to the workspace members and to the
+/// registry's dependencies and days.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<PathBuf, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day {}, expected 1 to 25", day));
+    }
+    let path = day_dir(year, day)
+        .to_string_lossy()
+        .into_owned();
+    let dir = root.join(&path);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    // Work out every edit before writing anything, so that a workspace which
+    // cannot be edited is left as it was
+    let workspace_manifest = root.join("Cargo.toml");
+    let member = format!("    \"{}\",", path);
+    let is_member = |line: &str| line.trim_start().starts_with('"') && line.contains("day-");
+    let members = ["members", "default-members"]
+        .into_iter()
+        .try_fold(read(&workspace_manifest)?, |text, key| {
+            add_array_entry(&text, key, &member, is_member).ok_or_else(|| {
+                format!(
+                    "Cannot find the {} in {}",
+                    key,
+                    workspace_manifest.display()
+                )
+            })
+        })?;
+    let registry_manifest = root.join("registry/Cargo.toml");
+    let dependencies = register(
+        &registry_manifest,
+        &format!("{} = {{ path = \"../{}\" }}", crate_name(year, day), path),
+        |line| line.contains("path = \"../") && line.contains("day-"),
+    )?;
+    let registry = root.join("registry/src/lib.rs");
+    let import = if year == DEFAULT_YEAR {
+        "use {{lib}}::Day{{padded}};"
+    } else {
+        "use {{lib}}::Day{{padded}} as {{solution}};"
+    };
+    let imports = register(&registry, &fill(import, year, day), |line| {
+        line.starts_with("use ") && line.contains("day_")
+    })?;
+    let days = add_entry(
+        &imports,
+        &fill("        Day::of::<{{solution}}>(),", year, day),
+        |line| {
+            line.trim_start()
+                .starts_with("Day::of::<")
+        },
+    )
+    .ok_or_else(|| format!("Cannot find the days in {}", registry.display()))?;
+
+    let src = dir.join("src");
+    fs::create_dir_all(&src)
+        .map_err(|error| format!("Cannot create {}: {}", src.display(), error))?;
+    write(&dir.join("Cargo.toml"), &fill(CARGO_TEMPLATE, year, day))?;
+    write(&src.join("lib.rs"), &fill(LIB_TEMPLATE, year, day))?;
+    write(&src.join("main.rs"), &fill(MAIN_TEMPLATE, year, day))?;
+    write(&dir.join("example.txt"), "")?;
+    write(&workspace_manifest, &members)?;
+    write(&registry_manifest, &dependencies)?;
+    write(&registry, &days)?;
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_stay_sorted() {
+        let text = "[dependencies]\ncommon = 1\nday-01 = 1\nday-03 = 1\n\n[features]\n";
+        let is_entry = |line: &str| line.starts_with("day-");
+        assert_eq!(
+            add_entry(text, "day-02 = 1", is_entry).unwrap(),
+            "[dependencies]\ncommon = 1\nday-01 = 1\nday-02 = 1\nday-03 = 1\n\n[features]\n"
+        );
+        assert_eq!(
+            add_entry(text, "day-10 = 1", is_entry).unwrap(),
+            "[dependencies]\ncommon = 1\nday-01 = 1\nday-03 = 1\nday-10 = 1\n\n[features]\n"
+        );
+        assert_eq!(add_entry("[dependencies]\n", "day-10 = 1", is_entry), None);
+    }
+
+    #[test]
+    fn entries_go_on_over_indented_lines() {
+        let text = "vec![\n    a(),\n    c()\n        .d(),\n]\n";
+        let is_entry = |line: &str| line.starts_with("    ") && !line.starts_with("     ");
+        assert_eq!(
+            add_entry(text, "    b(),", is_entry).unwrap(),
+            "vec![\n    a(),\n    b(),\n    c()\n        .d(),\n]\n"
+        );
+        assert_eq!(
+            add_entry(text, "    e(),", is_entry).unwrap(),
+            "vec![\n    a(),\n    c()\n        .d(),\n    e(),\n]\n"
+        );
+    }
+
+    #[test]
+    fn array_entries_go_in_their_own_array() {
+        let text = "[workspace]\nmembers = [\n    \"day-01\",\n    \"python\",\n]\n\ndefault-members = [\n    \"day-01\",\n]\n";
+        let is_member = |line: &str| line.contains("day-");
+        let text = add_array_entry(text, "members", "    \"day-02\",", is_member).unwrap();
+        assert_eq!(
+            text,
+            "[workspace]\nmembers = [\n    \"day-01\",\n    \"day-02\",\n    \"python\",\n]\n\ndefault-members = [\n    \"day-01\",\n]\n"
+        );
+        assert_eq!(
+            add_array_entry(&text, "default-members", "    \"day-02\",", is_member).unwrap(),
+            "[workspace]\nmembers = [\n    \"day-01\",\n    \"day-02\",\n    \"python\",\n]\n\ndefault-members = [\n    \"day-01\",\n    \"day-02\",\n]\n"
+        );
+        assert_eq!(
+            add_array_entry(&text, "exclude", "    \"day-02\",", is_member),
+            None
+        );
+    }
+
+    #[test]
+    fn templates_name_the_day() {
+        let lib = fill(LIB_TEMPLATE, DEFAULT_YEAR, 7);
+        assert!(lib.contains("pub struct Day07;"));
+        assert!(lib.contains("const DAY: u8 = 7;"));
+        assert!(lib.contains("#[ignore = \"part one is not solved yet\"]"));
+        assert!(!lib.contains("{{"));
+        let main = fill(MAIN_TEMPLATE, DEFAULT_YEAR, 7);
+        assert!(main.contains("use day_07::Day07;"));
+    }
+
+    #[test]
+    fn templates_of_other_years_name_the_year() {
+        let manifest = fill(CARGO_TEMPLATE, 2022, 7);
+        assert!(manifest.contains("name = \"y2022-day-07\""));
+        assert!(manifest.contains("common = { path = \"../../common\" }"));
+        let lib = fill(LIB_TEMPLATE, 2022, 7);
+        assert!(lib.contains("const YEAR: u16 = 2022;"));
+        let main = fill(MAIN_TEMPLATE, 2022, 7);
+        assert!(main.contains("use y2022_day_07::Day07;"));
+    }
+}
@@ -0,0 +1,256 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use common::{Day, InputId, Ledger, Part, Rejection, Response, Submitter};
+
+use crate::args::Args;
+
+/// Remembers between runs when the server next takes an answer, as seconds
+/// since the Unix epoch in a file next to the ledger.
+struct Throttle {
+    path: PathBuf,
+}
+
+impl Throttle {
+    fn next_to(ledger: &Path) -> Self {
+        Throttle {
+            path: ledger.with_extension("wait"),
+        }
+    }
+
+    fn remaining(&self) -> Option<Duration> {
+        let until = fs::read_to_string(&self.path)
+            .ok()?
+            .trim()
+            .parse::<u64>()
+            .ok()?;
+        (UNIX_EPOCH + Duration::from_secs(until))
+            .duration_since(SystemTime::now())
+            .ok()
+    }
+
+    fn wait(&self, wait: Duration) -> io::Result<()> {
+        let until = SystemTime::now() + wait;
+        let until = until
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        fs::write(&self.path, format!("{}\n", until.as_secs()))
+    }
+}
+
+fn describe(rejection: Rejection) -> &'static str {
+    match rejection {
+        Rejection::Wrong => "wrong",
+        Rejection::TooHigh => "too high",
+        Rejection::TooLow => "too low",
+    }
+}
+
+/// Runs `part` of `day` and posts its answer, unless the ledger at
+/// `ledger_path` already accepted an answer or rules this one out, or the
+/// server asked to wait. Records what the server said in the ledger. Returns
+/// whether the answer is accepted.
+pub fn submit(day: &Day, part: Part, args: &Args, ledger_path: &Path) -> bool {
+    let label = format!("Day {}, part {}", day.number, part);
+    let input = match args.input_source(day).read() {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Day {}: {}", day.number, error);
+            return false;
+        }
+    };
+    let answer = day
+        .run(&input, &[part])
+        .and_then(|outcome| {
+            outcome
+                .parts
+                .into_iter()
+                .next()
+                .unwrap()
+                .answer
+        });
+    let answer = match answer {
+        Ok(Some(answer)) => answer,
+        Ok(None) => {
+            eprintln!("{}: no answer to submit", label);
+            return false;
+        }
+        Err(error) => {
+            eprintln!("{}: {}", label, error);
+            return false;
+        }
+    };
+
+    submit_answer(
+        &mut Submitter::from_env(),
+        day,
+        part,
+        InputId::of(&input),
+        &answer,
+        ledger_path,
+    )
+}
+
+/// Posts `answer` for `part` of `day` with `submitter` and records the
+/// verdict, unless the ledger or the throttle rules it out.
+fn submit_answer(
+    submitter: &mut Submitter,
+    day: &Day,
+    part: Part,
+    input_id: InputId,
+    answer: &str,
+    ledger_path: &Path,
+) -> bool {
+    let label = format!("Day {}, part {}", day.number, part);
+    let mut ledger = match Ledger::load(ledger_path) {
+        Ok(ledger) => ledger,
+        Err(error) => {
+            eprintln!("Ledger {}: {}", ledger_path.display(), error);
+            return false;
+        }
+    };
+    if let Some(accepted) = ledger.answer(day.number, part, input_id) {
+        if accepted == answer {
+            println!("{}: {} was already accepted", label, answer);
+            return true;
+        }
+        eprintln!(
+            "{}: not submitting {}, {} was already accepted",
+            label, answer, accepted
+        );
+        return false;
+    }
+    if let Some(rejection) = ledger.rejection(day.number, part, input_id, answer) {
+        eprintln!(
+            "{}: not submitting {}, which is known to be {}",
+            label,
+            answer,
+            describe(rejection)
+        );
+        return false;
+    }
+    let throttle = Throttle::next_to(ledger_path);
+    if let Some(remaining) = throttle.remaining() {
+        eprintln!(
+            "{}: the server takes answers again in {}s",
+            label,
+            remaining.as_secs() + 1
+        );
+        return false;
+    }
+
+    let response = match submitter.submit(day.year, day.number, part, answer) {
+        Ok(response) => response,
+        Err(error) => {
+            eprintln!("{}: {}", label, error);
+            return false;
+        }
+    };
+    let (accepted, wait) = match response {
+        Response::Right => {
+            println!("{}: {} is right", label, answer);
+            ledger.record(day.number, part, input_id, answer);
+            (true, None)
+        }
+        Response::Wrong { rejection, wait } => {
+            println!("{}: {} is {}", label, answer, describe(rejection));
+            ledger.record_rejection(day.number, part, input_id, answer, rejection);
+            (false, wait)
+        }
+        Response::TooSoon { wait } => {
+            eprintln!("{}: an answer was submitted too recently", label);
+            (false, wait)
+        }
+        Response::WrongLevel => {
+            eprintln!(
+                "{}: the server does not take this part, it is solved already or part 1 is not",
+                label
+            );
+            (false, None)
+        }
+        Response::Unrecognised(text) => {
+            eprintln!("{}: unrecognised response: {}", label, text);
+            (false, None)
+        }
+    };
+    if let Some(wait) = wait {
+        if let Err(error) = throttle.wait(wait) {
+            eprintln!("Cannot write {}: {}", throttle.path.display(), error);
+        }
+    }
+    if let Err(error) = ledger.save() {
+        eprintln!("Cannot write {}: {}", ledger.path().display(), error);
+        return false;
+    }
+    accepted
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use common::test_server::{response, serve};
+
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn answers_are_checked_against_the_ledger_and_throttle() {
+        let (address, server) = serve(vec![
+            response(
+                "200 OK",
+                &page("That's not the right answer; your answer is too low. Please wait one minute before trying again."),
+            ),
+            response("200 OK", &page("That's the right answer!")),
+        ]);
+        let mut submitter = Submitter::new(&address, Some("secret"));
+        let dir = env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let ledger_path = dir.join("answers.txt");
+        let throttle_path = dir.join("answers.wait");
+        let day = &registry::days()[0];
+        let input = InputId::of("1abc2\n");
+        let mut submit =
+            |answer| submit_answer(&mut submitter, day, Part::One, input, answer, &ledger_path);
+
+        assert!(!submit("41"));
+        let ledger = Ledger::load(&ledger_path).unwrap();
+        assert_eq!(
+            ledger.rejection(day.number, Part::One, input, "41"),
+            Some(Rejection::TooLow)
+        );
+        assert!(fs::read_to_string(&throttle_path).is_ok());
+
+        // Refused without asking the server: known to be too low, then throttled
+        assert!(!submit("40"));
+        assert!(!submit("42"));
+        fs::remove_file(&throttle_path).unwrap();
+
+        assert!(submit("42"));
+        let ledger = Ledger::load(&ledger_path).unwrap();
+        assert_eq!(ledger.answer(day.number, Part::One, input), Some("42"));
+        assert!(!throttle_path.exists());
+
+        // Refused without asking the server: already accepted
+        assert!(submit("42"));
+        assert!(!submit("43"));
+
+        let requests = server.join().unwrap();
+        let bodies = requests
+            .iter()
+            .map(|request| request.body.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(bodies, ["level=1&answer=41", "level=1&answer=42"]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
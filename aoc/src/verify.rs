@@ -0,0 +1,218 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+};
+
+use common::{Day, InputId, Ledger, Verdict};
+
+use crate::{args::Args, parallel::panic_message};
+
+#[derive(Default)]
+struct Tally {
+    matched: usize,
+    mismatched: usize,
+    unknown: usize,
+    missing: usize,
+    failed: usize,
+}
+
+impl Tally {
+    fn succeeded(&self, allow_missing: bool) -> bool {
+        self.mismatched == 0 && self.failed == 0 && (allow_missing || self.missing == 0)
+    }
+}
+
+fn verify_day(day: &Day, args: &Args, ledger: &mut Ledger, record: bool, tally: &mut Tally) {
+    let input = match args.input_source(day).read() {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Day {}: {}", day.number, error);
+            tally.failed += args.parts.len();
+            return;
+        }
+    };
+    let input_id = InputId::of(&input);
+    // A day which panics fails its checks without stopping the others
+    let outcome = match panic::catch_unwind(AssertUnwindSafe(|| day.run(&input, &args.parts))) {
+        Ok(Ok(outcome)) => outcome,
+        Ok(Err(error)) => {
+            eprintln!("Day {}: {}", day.number, error);
+            tally.failed += args.parts.len();
+            return;
+        }
+        Err(payload) => {
+            eprintln!("Day {}: panicked: {}", day.number, panic_message(payload));
+            tally.failed += args.parts.len();
+            return;
+        }
+    };
+    for part in outcome.parts {
+        let label = format!("Day {}, part {}", day.number, part.part);
+        let answer = match part.answer {
+            Ok(answer) => answer,
+            Err(error) => {
+                eprintln!("{}: {}", label, error);
+                tally.failed += 1;
+                continue;
+            }
+        };
+        match ledger.check(day.number, part.part, input_id, answer.as_deref()) {
+            Verdict::Match => {
+                println!("{}: match", label);
+                tally.matched += 1;
+            }
+            Verdict::Mismatch { expected } => {
+                println!(
+                    "{}: MISMATCH, answered {} but {} was accepted",
+                    label,
+                    answer.unwrap_or_default(),
+                    expected
+                );
+                tally.mismatched += 1;
+            }
+            Verdict::Rejected { rejection } => {
+                println!(
+                    "{}: MISMATCH, answered {} which was rejected as {}",
+                    label,
+                    answer.unwrap_or_default(),
+                    rejection
+                );
+                tally.mismatched += 1;
+            }
+            Verdict::Unknown => {
+                let answer = answer.unwrap_or_default();
+                if record && ledger.record(day.number, part.part, input_id, &answer) {
+                    println!("{}: unknown, recorded {}", label, answer);
+                } else {
+                    println!("{}: unknown, answered {}", label, answer);
+                }
+                tally.unknown += 1;
+            }
+            Verdict::Missing { expected: None } => {
+                println!("{}: missing", label);
+                tally.missing += 1;
+            }
+            Verdict::Missing {
+                expected: Some(expected),
+            } => {
+                println!("{}: missing, {} was accepted", label, expected);
+                tally.missing += 1;
+            }
+        }
+    }
+}
+
+/// Runs `days` and compares each answer with the ledger at `path`, printing a
+/// verdict per part and a summary. With `record`, answers the ledger does not
+/// know yet are accepted into it. Returns whether no answer mismatched or
+/// failed, and none was missing unless `allow_missing`.
+pub fn verify(days: &[Day], args: &Args, path: &Path, record: bool, allow_missing: bool) -> bool {
+    let mut ledger = match Ledger::load(path) {
+        Ok(ledger) => ledger,
+        Err(error) => {
+            eprintln!("Ledger {}: {}", path.display(), error);
+            return false;
+        }
+    };
+    let mut tally = Tally::default();
+    for day in days {
+        verify_day(day, args, &mut ledger, record, &mut tally);
+    }
+    println!(
+        "{} matched, {} mismatched, {} unknown, {} missing, {} failed",
+        tally.matched, tally.mismatched, tally.unknown, tally.missing, tally.failed
+    );
+    if record && tally.unknown > 0 {
+        if let Err(error) = ledger.save() {
+            eprintln!("Cannot write {}: {}", ledger.path().display(), error);
+            return false;
+        }
+    }
+    tally.succeeded(allow_missing)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use common::Solution;
+
+    use super::*;
+
+    struct Panicking;
+
+    impl Solution for Panicking {
+        const DAY: u8 = 1;
+
+        type Input = ();
+
+        fn parse(_input: &str) -> common::Result<Self::Input> {
+            Ok(())
+        }
+
+        fn part_one(_input: &Self::Input) -> common::Result<Option<String>> {
+            panic!("Part one panics")
+        }
+    }
+
+    struct Answering;
+
+    impl Solution for Answering {
+        const DAY: u8 = 2;
+
+        type Input = ();
+
+        fn parse(_input: &str) -> common::Result<Self::Input> {
+            Ok(())
+        }
+
+        fn part_one(_input: &Self::Input) -> common::Result<Option<String>> {
+            Ok(Some(String::from("1")))
+        }
+    }
+
+    #[test]
+    fn panicking_days_fail_without_stopping_the_others() {
+        let dir = env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day-01.txt"), "").unwrap();
+        fs::write(dir.join("day-02.txt"), "").unwrap();
+        let args = Args::parse(
+            ["verify", "--input-dir", dir.to_str().unwrap()]
+                .into_iter()
+                .map(String::from),
+        )
+        .unwrap();
+        let mut ledger = Ledger::load(&dir.join("answers.txt")).unwrap();
+        let mut tally = Tally::default();
+
+        for day in [Day::of::<Panicking>(), Day::of::<Answering>()] {
+            verify_day(&day, &args, &mut ledger, false, &mut tally);
+        }
+        assert_eq!(tally.failed, 2);
+        assert_eq!(tally.unknown, 1);
+        assert_eq!(tally.missing, 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn missing_answers_fail_unless_allowed() {
+        let tally = Tally {
+            matched: 3,
+            missing: 1,
+            ..Tally::default()
+        };
+        assert!(!tally.succeeded(false));
+        assert!(tally.succeeded(true));
+        assert!(Tally::default().succeeded(false));
+    }
+
+    #[test]
+    fn mismatches_always_fail() {
+        let tally = Tally {
+            mismatched: 1,
+            ..Tally::default()
+        };
+        assert!(!tally.succeeded(true));
+    }
+}
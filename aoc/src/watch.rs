@@ -0,0 +1,272 @@
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use common::{day_dir, Day, InputSource};
+
+use crate::{args::Args, output::TSV_HEADER, scaffold::workspace_root};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// When each watched file was last modified.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+fn add_files(path: &Path, snapshot: &mut Snapshot) {
+    let Ok(metadata) = fs::metadata(path) else {
+        // Not created yet, or removed since its directory was listed
+        return;
+    };
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path)
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            add_files(&entry.path(), snapshot);
+        }
+    } else if let Ok(modified) = metadata.modified() {
+        snapshot.insert(path.to_owned(), modified);
+    }
+}
+
+fn take_snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    for path in paths {
+        add_files(path, &mut snapshot);
+    }
+    snapshot
+}
+
+/// A file which was added, modified or removed between two snapshots.
+fn find_change(before: &Snapshot, after: &Snapshot) -> Option<PathBuf> {
+    after
+        .iter()
+        .find(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path)
+        .or_else(|| {
+            before
+                .keys()
+                .find(|path| !after.contains_key(*path))
+        })
+        .cloned()
+}
+
+/// Blocks until a watched file changes and then stays unchanged for a poll,
+/// as editors often save in several steps. Returns the first file changed.
+fn wait_for_change(paths: &[PathBuf], snapshot: &mut Snapshot) -> PathBuf {
+    let changed = loop {
+        thread::sleep(POLL_INTERVAL);
+        let next = take_snapshot(paths);
+        if let Some(changed) = find_change(snapshot, &next) {
+            *snapshot = next;
+            break changed;
+        }
+    };
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let next = take_snapshot(paths);
+        if next == *snapshot {
+            return changed;
+        }
+        *snapshot = next;
+    }
+}
+
+/// One part's result, read back from the runner's TSV output.
+#[derive(Debug, PartialEq)]
+struct PartRun {
+    part: String,
+    status: String,
+    answer: String,
+    time: Option<Duration>,
+}
+
+impl PartRun {
+    /// The answer, or what stands in for one.
+    fn result(&self) -> String {
+        match self.status.as_str() {
+            "solved" => self.answer.clone(),
+            "unsolved" => String::from("unsolved"),
+            _ => format!("error: {}", self.answer),
+        }
+    }
+}
+
+/// Reads the runner's TSV output, or `None` if it never ran because the
+/// build failed.
+fn parse_runs(tsv: &str) -> Option<Vec<PartRun>> {
+    let mut lines = tsv.lines();
+    if lines.next()? != TSV_HEADER {
+        return None;
+    }
+    let runs = lines
+        .filter_map(|line| {
+            let fields = line.split('\t').collect::<Vec<_>>();
+            let [_, _, part, status, answer, _, time] = fields[..] else {
+                return None;
+            };
+            Some(PartRun {
+                part: part.to_owned(),
+                status: status.to_owned(),
+                answer: answer.to_owned(),
+                time: time
+                    .parse()
+                    .ok()
+                    .map(Duration::from_nanos),
+            })
+        })
+        .collect();
+    Some(runs)
+}
+
+fn time_change(before: Duration, after: Duration) -> String {
+    if before.is_zero() {
+        return format!("from {:.2?}", before);
+    }
+    let change = (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+    format!("{:+.0}% from {:.2?}", change, before)
+}
+
+/// One line for a part, saying how it differs from the previous run.
+fn describe(run: &PartRun, previous: Option<&PartRun>) -> String {
+    let mut line = format!("Part {}: {}", run.part, run.result());
+    if let Some(previous) = previous {
+        if previous.result() != run.result() {
+            line += &format!(" (was {})", previous.result());
+        }
+    }
+    if let Some(time) = run.time {
+        line += &format!(" in {:.2?}", time);
+        if let Some(before) = previous.and_then(|previous| previous.time) {
+            line += &format!(" ({})", time_change(before, time));
+        }
+    }
+    line
+}
+
+/// Rebuilds the runner and runs `day` with it. Build errors go straight to
+/// stderr.
+fn build_and_run(day: &Day, args: &Args, root: &Path) -> io::Result<Option<Vec<PartRun>>> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command
+        .args(["run", "--release", "--quiet", "--manifest-path"])
+        .arg(root.join("Cargo.toml"))
+        .args(["-p", "aoc", "--"])
+        .arg(day.year.to_string())
+        .arg(day.number.to_string())
+        .args(["--format", "tsv"]);
+    if let [part] = args.parts[..] {
+        command.args(["--part", &part.to_string()]);
+    }
+    if let Some(input) = &args.input {
+        command.args(["--input", input]);
+    }
+    if let Some(input_dir) = &args.input_dir {
+        command
+            .arg("--input-dir")
+            .arg(input_dir);
+    }
+    if args.stream {
+        command.arg("--stream");
+    }
+    let output = command
+        .stderr(Stdio::inherit())
+        .output()?;
+    Ok(parse_runs(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Rebuilds and re-runs `day` whenever its source, the common crate or its
+/// input changes, printing how each answer and its time moved since the
+/// previous run. Only returns, with `false`, if cargo cannot be started.
+pub fn watch(day: &Day, args: &Args) -> bool {
+    let root = workspace_root();
+    let mut paths = vec![
+        root.join(day_dir(day.year, day.number))
+            .join("src"),
+        root.join("common").join("src"),
+    ];
+    if let InputSource::File(input) = args.input_source(day) {
+        paths.push(input);
+    }
+    let mut snapshot = take_snapshot(&paths);
+    let mut previous: Option<Vec<PartRun>> = None;
+    let mut reason = String::from("Starting");
+    loop {
+        println!("{}, running day {}", reason, day.number);
+        match build_and_run(day, args, &root) {
+            Ok(Some(runs)) => {
+                for run in &runs {
+                    let before = previous
+                        .iter()
+                        .flatten()
+                        .find(|before| before.part == run.part);
+                    println!("{}", describe(run, before));
+                }
+                previous = Some(runs);
+            }
+            Ok(None) => println!("Build failed"),
+            Err(error) => {
+                eprintln!("Cannot run cargo: {}", error);
+                return false;
+            }
+        }
+        let changed = wait_for_change(&paths, &mut snapshot);
+        reason = format!(
+            "{} changed",
+            changed
+                .strip_prefix(&root)
+                .unwrap_or(&changed)
+                .display()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(status: &str, answer: &str, nanos: u64) -> PartRun {
+        PartRun {
+            part: String::from("1"),
+            status: String::from(status),
+            answer: String::from(answer),
+            time: Some(Duration::from_nanos(nanos)),
+        }
+    }
+
+    #[test]
+    fn runs_are_read_from_tsv() {
+        let tsv = format!(
+            "{}\n2023\t5\t1\tsolved\t35\t1000\t2000\n2023\t5\t2\tunsolved\t\t1000\t50\n",
+            TSV_HEADER
+        );
+        let runs = parse_runs(&tsv).unwrap();
+        assert_eq!(runs[0], run("solved", "35", 2000));
+        assert_eq!(runs[1].result(), "unsolved");
+        assert_eq!(parse_runs(""), None);
+    }
+
+    #[test]
+    fn changes_are_described() {
+        let before = run("solved", "36", 2_000_000);
+        assert_eq!(
+            describe(&run("solved", "35", 1_000_000), Some(&before)),
+            "Part 1: 35 (was 36) in 1.00ms (-50% from 2.00ms)"
+        );
+        assert_eq!(
+            describe(&run("solved", "36", 3_000_000), Some(&before)),
+            "Part 1: 36 in 3.00ms (+50% from 2.00ms)"
+        );
+        assert_eq!(
+            describe(&run("error", "Overflow", 0), None),
+            "Part 1: error: Overflow in 0.00ns"
+        );
+    }
+}
@@ -0,0 +1,175 @@
+/// What a piece of code allocated on the thread it ran on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Allocations and reallocations made.
+    pub allocations: u64,
+    /// Bytes asked for over every allocation, including those freed since.
+    pub allocated_bytes: u64,
+    /// The most bytes held at once, beyond what was held before.
+    pub peak_bytes: u64,
+}
+
+/// Runs `f`, counting what it allocates on this thread. Gives `None` for the
+/// counts unless common is built with the `alloc-stats` feature, which
+/// replaces the global allocator with one that counts.
+pub fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let (value, stats) = counting::measure(f);
+        (value, Some(stats))
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    use super::AllocStats;
+
+    /// Running totals for one thread. Memory freed by another thread than
+    /// the one which allocated it makes `live` drift, which is why it is
+    /// signed, but days run on a single thread.
+    #[derive(Clone, Copy)]
+    struct Counters {
+        allocations: u64,
+        allocated_bytes: u64,
+        live: i64,
+        peak: i64,
+    }
+
+    thread_local! {
+        // Const and without a destructor, so using it never allocates
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters {
+                allocations: 0,
+                allocated_bytes: 0,
+                live: 0,
+                peak: 0,
+            })
+        };
+    }
+
+    fn record(allocated: usize, freed: usize) {
+        // Fails while the thread is being torn down, when nothing is measured
+        let _ = COUNTERS.try_with(|counters| {
+            let mut current = counters.get();
+            if allocated > 0 {
+                current.allocations += 1;
+                current.allocated_bytes += allocated as u64;
+            }
+            current.live += allocated as i64 - freed as i64;
+            current.peak = current.peak.max(current.live);
+            counters.set(current);
+        });
+    }
+
+    /// Forwards to the system allocator, counting as it goes.
+    pub struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let pointer = System.alloc(layout);
+            if !pointer.is_null() {
+                record(layout.size(), 0);
+            }
+            pointer
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let pointer = System.alloc_zeroed(layout);
+            if !pointer.is_null() {
+                record(layout.size(), 0);
+            }
+            pointer
+        }
+
+        unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+            System.dealloc(pointer, layout);
+            record(0, layout.size());
+        }
+
+        unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_pointer = System.realloc(pointer, layout, new_size);
+            if !new_pointer.is_null() {
+                record(new_size, layout.size());
+            }
+            new_pointer
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+        let before = COUNTERS.with(Cell::get);
+        // Measure the peak from here, then put back the outer one, in case
+        // this runs within another measurement
+        COUNTERS.with(|counters| {
+            counters.set(Counters {
+                peak: before.live,
+                ..before
+            })
+        });
+        let value = f();
+        let after = COUNTERS.with(Cell::get);
+        COUNTERS.with(|counters| {
+            counters.set(Counters {
+                peak: after.peak.max(before.peak),
+                ..after
+            })
+        });
+        let stats = AllocStats {
+            allocations: after.allocations - before.allocations,
+            allocated_bytes: after.allocated_bytes - before.allocated_bytes,
+            peak_bytes: (after.peak - before.live) as u64,
+        };
+        (value, stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "alloc-stats"))]
+    #[test]
+    fn nothing_is_counted_without_the_feature() {
+        assert_eq!(count_allocations(|| vec![1u8; 10]).1, None);
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn allocations_and_peak_are_counted() {
+        let ((), stats) = count_allocations(|| {
+            let first = vec![0u8; 1000];
+            drop(first);
+            let mut second = Vec::<u8>::with_capacity(100);
+            second.reserve_exact(400);
+        });
+        let stats = stats.unwrap();
+        assert_eq!(stats.allocations, 3);
+        assert_eq!(stats.allocated_bytes, 1500);
+        assert_eq!(stats.peak_bytes, 1000);
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn nested_measurements_count_in_both() {
+        let ((_, inner), outer) = count_allocations(|| {
+            let held = vec![0u8; 64];
+            let inner = count_allocations(|| vec![0u8; 32]);
+            drop(held);
+            inner
+        });
+        let (inner, outer) = (inner.unwrap(), outer.unwrap());
+        assert_eq!((inner.allocations, inner.peak_bytes), (1, 32));
+        assert_eq!((outer.allocations, outer.peak_bytes), (2, 96));
+    }
+}
@@ -0,0 +1,128 @@
+use std::{fmt, io};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Anything that can go wrong while reading, parsing or solving a puzzle.
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        source: String,
+        error: io::Error,
+    },
+    Parse(ParseError),
+    /// The input parsed, but describes a puzzle with no answer.
+    Domain(String),
+    /// The puzzle server could not be reached, or refused a request.
+    Http(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { source, error } => write!(f, "Cannot read {}: {}", source, error),
+            Error::Parse(error) => write!(f, "Parse error at {}", error),
+            Error::Domain(message) | Error::Http(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { error, .. } => Some(error),
+            Error::Parse(error) => Some(error),
+            Error::Domain(_) | Error::Http(_) => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+/// Failure to parse puzzle input. Lines and columns count from 1. A parser
+/// which only saw part of the input reports them relative to that part, and
+/// its caller moves them with [`ParseError::relative_to`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    /// Moves an error found in a piece of text which starts at `line` and
+    /// `column` of the whole input. An error without a location is put at the
+    /// start of that text.
+    pub fn relative_to(mut self, line: usize, column: usize) -> Self {
+        match self.line {
+            None | Some(1) => {
+                self.line = Some(line);
+                self.column = Some(self.column.unwrap_or(1) + column - 1);
+            }
+            Some(relative_line) => self.line = Some(line + relative_line - 1),
+        }
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "{}:{}: {}", line, column, self.message),
+            (Some(line), None) => write!(f, "line {}: {}", line, self.message),
+            _ => write!(f, "unknown location: {}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(line: Option<usize>, column: Option<usize>) -> ParseError {
+        ParseError {
+            message: String::from("oops"),
+            line,
+            column,
+        }
+    }
+
+    #[test]
+    fn errors_on_the_first_line_move_along_it() {
+        assert_eq!(at(Some(1), Some(4)).relative_to(3, 5), at(Some(3), Some(8)));
+    }
+
+    #[test]
+    fn errors_on_later_lines_keep_their_column() {
+        assert_eq!(at(Some(2), Some(4)).relative_to(3, 5), at(Some(4), Some(4)));
+    }
+
+    #[test]
+    fn unlocated_errors_go_to_the_start() {
+        assert_eq!(at(None, None).relative_to(3, 5), at(Some(3), Some(5)));
+    }
+
+    #[test]
+    fn locations_are_displayed() {
+        assert_eq!(at(Some(2), Some(4)).to_string(), "2:4: oops");
+        assert_eq!(at(Some(2), None).to_string(), "line 2: oops");
+        assert_eq!(at(None, None).to_string(), "unknown location: oops");
+        assert_eq!(
+            Error::from(at(Some(2), Some(4))).to_string(),
+            "Parse error at 2:4: oops"
+        );
+    }
+}
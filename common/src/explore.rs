@@ -0,0 +1,138 @@
+use crate::{Error, Result, Solution, Span};
+
+/// A command of a day's REPL, as listed by `help`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReplCommand {
+    pub name: &'static str,
+    /// What the command takes, such as `<seed>`.
+    pub args: &'static str,
+    pub help: &'static str,
+}
+
+/// A [`Solution`] whose parsed input can be questioned one command at a
+/// time, for exploring an input without editing the day's `main`.
+pub trait ExploringSolution: Solution {
+    const COMMANDS: &'static [ReplCommand];
+
+    /// Runs one of [`Self::COMMANDS`] with its `args`, giving what to print,
+    /// or gives `None` if `command` is not one of them.
+    fn explore(input: &Self::Input, command: &str, args: Span<'_>) -> Option<Result<String>>;
+}
+
+type ExploreFn = Box<dyn Fn(&str, Span<'_>) -> Option<Result<String>>>;
+
+/// A parsed input, kept to answer the commands of its day.
+pub struct Session {
+    pub commands: &'static [ReplCommand],
+    explore: ExploreFn,
+}
+
+impl Session {
+    /// Runs a line holding a command and its arguments, or gives `None` if
+    /// the day has no such command. A blank line runs nothing.
+    pub fn run(&self, line: &str) -> Option<Result<String>> {
+        match Span::new(line).split_first_word() {
+            Some((command, args)) => (self.explore)(command.as_str(), args),
+            None => Some(Ok(String::new())),
+        }
+    }
+
+    pub fn command(&self, name: &str) -> Option<&ReplCommand> {
+        self.commands
+            .iter()
+            .find(|command| command.name == name)
+    }
+}
+
+pub(crate) fn session<S: ExploringSolution>(input: &str) -> Result<Session>
+where
+    S::Input: 'static,
+{
+    let input = S::parse(input)?;
+    Ok(Session {
+        commands: S::COMMANDS,
+        explore: Box::new(move |command, args| S::explore(&input, command, args)),
+    })
+}
+
+/// An error for a command whose arguments name something the input does not
+/// have, such as a game it does not list.
+pub fn not_found(what: impl std::fmt::Display) -> Error {
+    Error::Domain(format!("No {} in the input", what))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Numbers;
+
+    impl Solution for Numbers {
+        const DAY: u8 = 1;
+
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(Span::new(input).numbers()?)
+        }
+    }
+
+    impl ExploringSolution for Numbers {
+        const COMMANDS: &'static [ReplCommand] = &[ReplCommand {
+            name: "nth",
+            args: "<n>",
+            help: "The nth number, counting from 1",
+        }];
+
+        fn explore(numbers: &Self::Input, command: &str, args: Span<'_>) -> Option<Result<String>> {
+            let nth = || -> Result<String> {
+                let [n] = args.split_words()?;
+                let n = n.value::<usize>()?;
+                numbers
+                    .get(n.wrapping_sub(1))
+                    .map(u32::to_string)
+                    .ok_or_else(|| not_found(format_args!("number {}", n)))
+            };
+            match command {
+                "nth" => Some(nth()),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn commands_run_against_the_parsed_input() {
+        let session = session::<Numbers>("4 8 15").unwrap();
+        assert_eq!(
+            session
+                .run("  nth   2 ")
+                .unwrap()
+                .unwrap(),
+            "8"
+        );
+        assert_eq!(session.run("").unwrap().unwrap(), "");
+        assert!(session.run("sum").is_none());
+        assert_eq!(session.command("nth"), Some(&Numbers::COMMANDS[0]));
+    }
+
+    #[test]
+    fn argument_errors_are_located_in_the_line() {
+        let session = session::<Numbers>("4 8 15").unwrap();
+        match session.run("nth x").unwrap() {
+            Err(Error::Parse(error)) => assert_eq!(error.column, Some(5)),
+            _ => panic!("Expected a parse error"),
+        }
+        match session.run("nth 1 2").unwrap() {
+            Err(Error::Parse(error)) => assert_eq!(error.message, "Expected 1 word, found 2"),
+            _ => panic!("Expected a parse error"),
+        }
+        assert_eq!(
+            session
+                .run("nth 4")
+                .unwrap()
+                .unwrap_err()
+                .to_string(),
+            "No number 4 in the input"
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{input_path, server::Server, Error, Result};
+
+/// Where [`Fetcher::fetch`] found an input.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// It was already on disk, so nothing was downloaded.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads puzzle inputs, keeping them where [`crate::InputSource`] looks
+/// for them and never downloading one twice.
+pub struct Fetcher {
+    server: Server,
+    input_dir: Option<PathBuf>,
+}
+
+impl Fetcher {
+    /// Caches inputs as `day-NN.txt` in `input_dir`, or next to each day's
+    /// crate without one. Without a session, only cached inputs are found.
+    pub fn new(base_url: &str, session: Option<&str>, input_dir: Option<&Path>) -> Self {
+        Fetcher {
+            server: Server::new(base_url, session),
+            input_dir: input_dir.map(Path::to_owned),
+        }
+    }
+
+    /// Like [`Fetcher::new`], taking the session from [`crate::SESSION_VAR`]
+    /// and the base URL from [`crate::BASE_URL_VAR`] if it is set.
+    pub fn from_env(input_dir: Option<&Path>) -> Self {
+        Fetcher {
+            server: Server::from_env(),
+            input_dir: input_dir.map(Path::to_owned),
+        }
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.server.min_interval = min_interval;
+        self
+    }
+
+    /// Makes sure the input of `day` of `year` is on disk, downloading it if
+    /// needed.
+    pub fn fetch(&mut self, year: u16, day: u8) -> Result<Fetched> {
+        let path = input_path(year, day, self.input_dir.as_deref());
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+        let input = self
+            .server
+            .request(year, &format!("day/{}/input", day), None)?;
+
+        // Written aside first, so that a failed write never looks cached
+        let io_error = |error| Error::Io {
+            source: path.display().to_string(),
+            error,
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let partial = path.with_extension("part");
+        fs::write(&partial, input).map_err(io_error)?;
+        fs::rename(&partial, &path).map_err(io_error)?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, time::Instant};
+
+    use super::*;
+    use crate::{
+        test_server::{response, serve},
+        DEFAULT_YEAR,
+    };
+
+    fn empty_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_once_then_uses_the_cache() {
+        let (address, server) = serve(vec![response("200 OK", "0 3 6 9\n")]);
+        let dir = empty_dir("cache");
+        let mut fetcher = Fetcher::new(&address, Some("secret\n"), Some(&dir));
+        let path = dir.join("day-09.txt");
+
+        assert_eq!(
+            fetcher.fetch(DEFAULT_YEAR, 9).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "0 3 6 9\n");
+        assert_eq!(
+            fetcher.fetch(DEFAULT_YEAR, 9).unwrap(),
+            Fetched::Cached(path)
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0]
+            .head
+            .starts_with("GET /2023/day/9/input HTTP/1.1\r\n"));
+        assert!(requests[0]
+            .head
+            .to_lowercase()
+            .contains("\r\ncookie: session=secret\r\n"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn refused_downloads_are_errors() {
+        let (address, server) = serve(vec![response("404 Not Found", "Not unlocked yet\n")]);
+        let dir = empty_dir("refused");
+        let mut fetcher = Fetcher::new(&address, Some("secret"), Some(&dir));
+
+        let error = fetcher
+            .fetch(DEFAULT_YEAR, 25)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.ends_with("answered 404: Not unlocked yet"),
+            "{}",
+            error
+        );
+        assert!(!dir.join("day-25.txt").exists());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn downloads_are_spaced_out() {
+        let responses = vec![response("200 OK", "1\n"), response("200 OK", "2\n")];
+        let (address, server) = serve(responses);
+        let dir = empty_dir("throttle");
+        let mut fetcher = Fetcher::new(&address, Some("secret"), Some(&dir))
+            .with_min_interval(Duration::from_millis(300));
+
+        let start = Instant::now();
+        fetcher.fetch(DEFAULT_YEAR, 1).unwrap();
+        fetcher.fetch(2022, 1).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        assert!(dir.join("2022/day-01.txt").exists());
+        let requests = server.join().unwrap();
+        assert!(requests[1]
+            .head
+            .starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
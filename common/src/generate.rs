@@ -0,0 +1,122 @@
+use std::ops::RangeInclusive;
+
+use crate::{Result, Solution};
+
+/// A small seeded random number generator (SplitMix64), so that generated
+/// inputs can be reproduced from their seed on any platform.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`.
+    ///
+    /// # Panics
+    ///
+    /// If `bound` is 0.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "Cannot pick a number below 0");
+        // Good enough for inputs, where a tiny bias does not matter
+        self.next_u64() % bound
+    }
+
+    pub fn between(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let span = (range.end() - range.start()) as u64 + 1;
+        range.start() + self.below(span) as i64
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    /// # Panics
+    ///
+    /// If `items` is empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// A generated puzzle input, with the answers of the parts whose answer the
+/// generator could work out without solving the puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+/// A [`Solution`] which can generate valid inputs of its puzzle, for testing
+/// it beyond the checked-in input.
+pub trait GeneratingSolution: Solution {
+    /// Generates an input of about `size` lines, or items where lines do not
+    /// fit the puzzle. Each generator documents how it reads `size`.
+    fn generate(rng: &mut Rng, size: usize) -> Generated;
+}
+
+/// A [`Solution`] which can rewrite a real input into another of the same
+/// shape, so that fixtures can be shared where the real input may not be.
+pub trait AnonymisingSolution: Solution {
+    /// Rewrites `input` so that it no longer gives the original away, keeping
+    /// what makes it a valid input. Each day documents what else it keeps,
+    /// such as its answers.
+    fn anonymise(input: &str, rng: &mut Rng) -> Result<String>;
+}
+
+/// Anonymises `input`, then solves the result for its answers.
+pub(crate) fn anonymise<S: AnonymisingSolution>(input: &str, rng: &mut Rng) -> Result<Generated> {
+    let input = S::anonymise(input, rng)?;
+    let parsed = S::parse(&input)?;
+    let answers = [S::part_one(&parsed)?, S::part_two(&parsed)?];
+    Ok(Generated { input, answers })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeds_reproduce() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5)
+                .map(|_| rng.next_u64())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+    }
+
+    #[test]
+    fn ranges_are_inclusive() {
+        let mut rng = Rng::new(0);
+        let mut seen = [false; 3];
+        for _ in 0..100 {
+            let n = rng.between(-1..=1);
+            seen[(n + 1) as usize] = true;
+        }
+        assert_eq!(seen, [true; 3]);
+    }
+}
@@ -0,0 +1,350 @@
+use std::{fmt, str::FromStr};
+
+use crate::{ParseError, Span};
+
+/// Position of a cell as `(row, column)`, counting from the top left.
+pub type Coordinates = (usize, usize);
+
+const ORTHOGONAL_OFFSETS: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+const SURROUNDING_OFFSETS: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangle of cells, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Coordinates) -> bool {
+        row < self.height && col < self.width
+    }
+
+    fn index(&self, (row, col): Coordinates) -> usize {
+        row * self.width + col
+    }
+
+    pub fn get(&self, pos: Coordinates) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[self.index(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Coordinates) -> Option<&mut T> {
+        self.contains(pos).then(|| {
+            let index = self.index(pos);
+            &mut self.cells[index]
+        })
+    }
+
+    fn offset_neighbors<'a>(
+        &'a self,
+        (row, col): Coordinates,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Coordinates> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&(row_off, col_off)| {
+                let pos = (
+                    row.checked_add_signed(row_off)?,
+                    col.checked_add_signed(col_off)?,
+                );
+                self.contains(pos).then_some(pos)
+            })
+    }
+
+    /// The cells above, left, right and below `pos` which are on the grid.
+    pub fn neighbors_4(&self, pos: Coordinates) -> impl Iterator<Item = Coordinates> + '_ {
+        self.offset_neighbors(pos, &ORTHOGONAL_OFFSETS)
+    }
+
+    /// The cells surrounding `pos`, diagonals included, which are on the grid.
+    pub fn neighbors_8(&self, pos: Coordinates) -> impl Iterator<Item = Coordinates> + '_ {
+        self.offset_neighbors(pos, &SURROUNDING_OFFSETS)
+    }
+
+    /// The cells of row `row`, left to right.
+    ///
+    /// # Panics
+    ///
+    /// If `row` is not on the grid.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "Row {} is outside of the grid", row);
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// The cells of column `col`, top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// If `col` is not on the grid.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "Column {} is outside of the grid", col);
+        self.cells
+            .iter()
+            .skip(col)
+            .step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Coordinates> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinates, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Maximal horizontal runs of matching cells, such as the numbers in an
+    /// engine schematic.
+    pub fn row_runs(&self, mut matches: impl FnMut(&T) -> bool) -> Vec<Vec<Coordinates>> {
+        let mut runs = vec![];
+        for (row, cells) in self.rows().enumerate() {
+            let mut run = vec![];
+            for (col, cell) in cells.iter().enumerate() {
+                if matches(cell) {
+                    run.push((row, col));
+                } else if !run.is_empty() {
+                    runs.push(std::mem::take(&mut run));
+                }
+            }
+            if !run.is_empty() {
+                runs.push(run);
+            }
+        }
+        runs
+    }
+
+    /// Groups of matching cells connected through their 4-neighbourhoods.
+    pub fn regions(&self, mut matches: impl FnMut(&T) -> bool) -> Vec<Vec<Coordinates>> {
+        let mut is_candidate = self
+            .cells
+            .iter()
+            .map(&mut matches)
+            .collect::<Vec<_>>();
+        let mut regions = vec![];
+        for start in self.positions() {
+            if !is_candidate[self.index(start)] {
+                continue;
+            }
+            is_candidate[self.index(start)] = false;
+            let mut region = vec![];
+            let mut to_visit = vec![start];
+            while let Some(pos) = to_visit.pop() {
+                region.push(pos);
+                for neighbor in self.neighbors_4(pos) {
+                    if is_candidate[self.index(neighbor)] {
+                        is_candidate[self.index(neighbor)] = false;
+                        to_visit.push(neighbor);
+                    }
+                }
+            }
+            regions.push(region);
+        }
+        regions
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = Span::new(s.trim_end_matches(['\r', '\n']));
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in input.lines() {
+            let row_width = line.as_str().chars().count();
+            match width {
+                Some(width) if width != row_width => {
+                    return Err(line.error(format!(
+                        "Expected a row of {} cells, found {}",
+                        width, row_width
+                    )));
+                }
+                _ => width = Some(row_width),
+            }
+            cells.extend(line.as_str().chars());
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(
+            grid.column(2)
+                .copied()
+                .collect::<String>(),
+            "cf"
+        );
+        let columns = grid
+            .columns()
+            .map(|column| column.copied().collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 2 is outside of the grid")]
+    fn rows_past_the_bottom_panic() {
+        grid().row(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Column 3 is outside of the grid")]
+    fn columns_past_the_edge_panic() {
+        let _ = grid().column(3);
+    }
+
+    #[test]
+    fn cells_off_the_grid_are_missing() {
+        let mut grid = grid();
+        assert_eq!(grid.get((1, 2)), Some(&'f'));
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get_mut((0, 3)), None);
+        assert_eq!(grid.get((usize::MAX, 0)), None);
+        assert_eq!(grid.get_mut((usize::MAX, 0)), None);
+        *grid.get_mut((0, 0)).unwrap() = 'x';
+        assert_eq!(grid.row(0), ['x', 'b', 'c']);
+    }
+
+    #[test]
+    fn neighbours_stop_at_the_edges() {
+        let grid = grid();
+        let neighbours = |neighbours: &mut dyn Iterator<Item = Coordinates>| {
+            let mut neighbours = neighbours.collect::<Vec<_>>();
+            neighbours.sort();
+            neighbours
+        };
+        assert_eq!(neighbours(&mut grid.neighbors_4((0, 0))), [(0, 1), (1, 0)]);
+        assert_eq!(
+            neighbours(&mut grid.neighbors_4((1, 1))),
+            [(0, 1), (1, 0), (1, 2)]
+        );
+        assert_eq!(
+            neighbours(&mut grid.neighbors_8((0, 2))),
+            [(0, 1), (1, 1), (1, 2)]
+        );
+        assert_eq!(grid.neighbors_8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn runs_end_at_the_end_of_each_row() {
+        let grid = "12.3\n4..5\n...."
+            .parse::<Grid<char>>()
+            .unwrap();
+        assert_eq!(
+            grid.row_runs(|cell| cell.is_ascii_digit()),
+            [
+                vec![(0, 0), (0, 1)],
+                vec![(0, 3)],
+                vec![(1, 0)],
+                vec![(1, 3)]
+            ]
+        );
+    }
+
+    #[test]
+    fn regions_connect_orthogonally() {
+        let grid = "##.\n..#\n.##"
+            .parse::<Grid<char>>()
+            .unwrap();
+        let mut regions = grid.regions(|&cell| cell == '#');
+        for region in &mut regions {
+            region.sort();
+        }
+        assert_eq!(
+            regions,
+            [vec![(0, 0), (0, 1)], vec![(1, 2), (2, 1), (2, 2)]]
+        );
+    }
+
+    #[test]
+    fn ragged_rows_are_errors() {
+        let error = "abc\nde\n"
+            .parse::<Grid<char>>()
+            .unwrap_err();
+        assert_eq!(error.to_string(), "2:1: Expected a row of 3 cells, found 2");
+        let empty = "".parse::<Grid<char>>().unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.columns().count(), 0);
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    env, fmt,
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{Error, Lines, Result, DEFAULT_YEAR};
+
+/// Environment variable naming a directory of personal inputs, holding one
+/// `day-NN.txt` file per day, with those of other years than the default in
+/// a `YYYY` subdirectory.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a day's puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Picks the input for `day` of `year`. An explicit argument wins (`-`
+    /// meaning stdin), then a file in `input_dir`, then the input checked in
+    /// next to the day's crate.
+    pub fn resolve(year: u16, day: u8, explicit: Option<&str>, input_dir: Option<&Path>) -> Self {
+        match (explicit, input_dir) {
+            (Some("-"), _) => InputSource::Stdin,
+            (Some(path), _) => InputSource::File(PathBuf::from(path)),
+            (None, input_dir) => InputSource::File(input_path(year, day, input_dir)),
+        }
+    }
+
+    /// Like [`InputSource::resolve`], taking the input directory from
+    /// [`INPUT_DIR_VAR`] if it is set.
+    pub fn for_day(year: u16, day: u8, explicit: Option<&str>) -> Self {
+        let input_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+        Self::resolve(year, day, explicit, input_dir.as_deref())
+    }
+
+    /// Resolves the input of a single-day binary, whose only argument is an
+    /// optional input path.
+    pub fn from_args(year: u16, day: u8) -> Self {
+        let explicit = env::args().nth(1);
+        Self::for_day(year, day, explicit.as_deref())
+    }
+
+    pub fn read(&self) -> Result<String> {
+        let mut input = String::new();
+        match self {
+            InputSource::Stdin => io::stdin()
+                .read_to_string(&mut input)
+                .map(|_| input),
+            InputSource::File(path) => std::fs::read_to_string(path),
+        }
+        .map_err(|error| Error::Io {
+            source: self.to_string(),
+            error,
+        })
+    }
+
+    /// Opens the input to be read one line at a time.
+    pub fn lines(&self) -> Result<Lines<Box<dyn BufRead>>> {
+        let reader: Box<dyn BufRead> = match self {
+            InputSource::Stdin => Box::new(io::stdin().lock()),
+            InputSource::File(path) => {
+                Box::new(BufReader::new(File::open(path).map_err(|error| {
+                    Error::Io {
+                        source: self.to_string(),
+                        error,
+                    }
+                })?))
+            }
+        };
+        Ok(Lines::new(reader, self))
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Where the workspace keeps what belongs to `year`, relative to its root:
+/// the root itself for the default year, and a `YYYY` directory for others.
+pub fn year_dir(year: u16) -> PathBuf {
+    if year == DEFAULT_YEAR {
+        PathBuf::new()
+    } else {
+        PathBuf::from(year.to_string())
+    }
+}
+
+/// Where the crate of `day` of `year` lives, relative to the workspace root.
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    year_dir(year).join(format!("day-{:02}", day))
+}
+
+/// Where the input of `day` of `year` is kept: as `day-NN.txt` in
+/// `input_dir` and its year's subdirectory, or checked in under the day's
+/// crate.
+pub fn input_path(year: u16, day: u8, input_dir: Option<&Path>) -> PathBuf {
+    match input_dir {
+        Some(dir) => dir.join(day_dir(year, day).with_extension("txt")),
+        None => bundled_input_path(year, day),
+    }
+}
+
+/// Path of the input checked in under the day's crate. It does not depend on
+/// the working directory.
+pub fn bundled_input_path(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("common should live inside the workspace")
+        .join(day_dir(year, day))
+        .join("input.txt")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn other_years_keep_to_their_directory() {
+        let dir = Path::new("inputs");
+        assert_eq!(
+            input_path(DEFAULT_YEAR, 5, Some(dir)),
+            Path::new("inputs/day-05.txt")
+        );
+        assert_eq!(
+            input_path(2022, 5, Some(dir)),
+            Path::new("inputs/2022/day-05.txt")
+        );
+        let root = bundled_input_path(DEFAULT_YEAR, 5)
+            .ancestors()
+            .nth(2)
+            .unwrap()
+            .to_owned();
+        assert_eq!(
+            bundled_input_path(2022, 5),
+            root.join("2022/day-05/input.txt")
+        );
+    }
+}
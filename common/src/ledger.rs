@@ -0,0 +1,430 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{year_dir, Error, ParseError, Part, Result, Span};
+
+const HEADER: &str =
+    "# Submitted answers: day, part, input fingerprint, answer, and why it was rejected if it was\n";
+
+/// Identifies a puzzle input by its contents, so that answers for different
+/// inputs of the same day can be told apart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InputId(u64);
+
+impl InputId {
+    /// Fingerprints `input` with 64-bit FNV-1a, which unlike the standard
+    /// library hashers is stable across Rust versions.
+    pub fn of(input: &str) -> Self {
+        let hash = input
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+            });
+        InputId(hash)
+    }
+}
+
+impl fmt::Display for InputId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+impl FromStr for InputId {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match u64::from_str_radix(s, 16) {
+            Ok(hash) if s.len() == 16 => Ok(InputId(hash)),
+            _ => Err(ParseError::new(format!(
+                "Expected a 16 digit input fingerprint, found `{}`",
+                s
+            ))),
+        }
+    }
+}
+
+/// Why the puzzle server turned an answer down.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rejection {
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Rejection::Wrong => "wrong",
+            Rejection::TooHigh => "too-high",
+            Rejection::TooLow => "too-low",
+        })
+    }
+}
+
+impl FromStr for Rejection {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "wrong" => Ok(Rejection::Wrong),
+            "too-high" => Ok(Rejection::TooHigh),
+            "too-low" => Ok(Rejection::TooLow),
+            _ => Err(format!(
+                "Invalid rejection {}, expected wrong, too-high or too-low",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Entry {
+    day: u8,
+    part: Part,
+    input: InputId,
+    answer: String,
+    /// `None` for an accepted answer.
+    rejection: Option<Rejection>,
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let line = Span::new(s);
+        let mut fields = line.words();
+        let mut field = |name: &str| {
+            fields
+                .next()
+                .ok_or_else(|| line.error(format!("Missing {}", name)))
+        };
+        let day = field("day")?.value()?;
+        let part = field("part")?;
+        let part = part
+            .as_str()
+            .parse()
+            .map_err(|message: String| part.error(message))?;
+        let input = field("input fingerprint")?.parse()?;
+        let answer = field("answer")?;
+        let rejection = fields
+            .next()
+            .map(|rejection| {
+                rejection
+                    .as_str()
+                    .parse()
+                    .map_err(|message: String| rejection.error(message))
+            })
+            .transpose()?;
+        if let Some(extra) = fields.next() {
+            return Err(extra.error("Expected nothing after the rejection"));
+        }
+        Ok(Entry {
+            day,
+            part,
+            input,
+            answer: answer.as_str().to_owned(),
+            rejection,
+        })
+    }
+}
+
+/// How an answer compares with the one accepted for the same input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch {
+        expected: String,
+    },
+    /// No answer has been accepted for this input yet, and this one has not
+    /// been ruled out either.
+    Unknown,
+    /// No answer has been accepted for this input yet, but this one has been
+    /// rejected.
+    Rejected {
+        rejection: Rejection,
+    },
+    /// The part gave no answer, whether or not one has been accepted.
+    Missing {
+        expected: Option<String>,
+    },
+}
+
+/// Answers which have been submitted, per day, part and input, kept in a text
+/// file with one answer per line. At most one answer is accepted for each
+/// input, after any number of rejected ones.
+#[derive(Clone, Debug)]
+pub struct Ledger {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl Ledger {
+    /// Reads the ledger at `path`. A ledger which does not exist yet is
+    /// empty.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(error) => {
+                return Err(Error::Io {
+                    source: path.display().to_string(),
+                    error,
+                })
+            }
+        };
+        let entries = Span::new(&text)
+            .lines()
+            .filter(|line| {
+                let line = line.as_str().trim();
+                !line.is_empty() && !line.starts_with('#')
+            })
+            .map(|line| line.parse())
+            .collect::<std::result::Result<_, _>>()?;
+        Ok(Ledger {
+            path: path.to_owned(),
+            entries,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn entries(&self, day: u8, part: Part, input: InputId) -> impl Iterator<Item = &Entry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.day == day && entry.part == part && entry.input == input)
+    }
+
+    /// The accepted answer.
+    pub fn answer(&self, day: u8, part: Part, input: InputId) -> Option<&str> {
+        self.entries(day, part, input)
+            .find(|entry| entry.rejection.is_none())
+            .map(|entry| entry.answer.as_str())
+    }
+
+    /// Whether `answer` is wrong by what the server said of earlier ones: it
+    /// was rejected itself, or it is a number past one rejected as too high
+    /// or too low.
+    pub fn rejection(
+        &self,
+        day: u8,
+        part: Part,
+        input: InputId,
+        answer: &str,
+    ) -> Option<Rejection> {
+        let number = answer.parse::<i128>().ok();
+        self.entries(day, part, input)
+            .find_map(|entry| {
+                let rejection = entry.rejection?;
+                if entry.answer == answer {
+                    return Some(rejection);
+                }
+                let bound = entry.answer.parse::<i128>().ok()?;
+                match rejection {
+                    Rejection::TooHigh if number? >= bound => Some(rejection),
+                    Rejection::TooLow if number? <= bound => Some(rejection),
+                    _ => None,
+                }
+            })
+    }
+
+    pub fn check(&self, day: u8, part: Part, input: InputId, answer: Option<&str>) -> Verdict {
+        let expected = self.answer(day, part, input);
+        match (answer, expected) {
+            (None, expected) => Verdict::Missing {
+                expected: expected.map(str::to_owned),
+            },
+            (Some(answer), None) => match self.rejection(day, part, input, answer) {
+                Some(rejection) => Verdict::Rejected { rejection },
+                None => Verdict::Unknown,
+            },
+            (Some(answer), Some(expected)) if answer == expected => Verdict::Match,
+            (Some(_), Some(expected)) => Verdict::Mismatch {
+                expected: expected.to_owned(),
+            },
+        }
+    }
+
+    /// Accepts `answer` unless an answer is already accepted for the same
+    /// input, returning whether it was added.
+    pub fn record(&mut self, day: u8, part: Part, input: InputId, answer: &str) -> bool {
+        if self.answer(day, part, input).is_some() {
+            return false;
+        }
+        self.entries.push(Entry {
+            day,
+            part,
+            input,
+            answer: answer.to_owned(),
+            rejection: None,
+        });
+        true
+    }
+
+    /// Notes that `answer` was rejected, unless it is already accepted or
+    /// rejected, returning whether it was added.
+    pub fn record_rejection(
+        &mut self,
+        day: u8,
+        part: Part,
+        input: InputId,
+        answer: &str,
+        rejection: Rejection,
+    ) -> bool {
+        if self
+            .entries(day, part, input)
+            .any(|entry| entry.answer == answer)
+        {
+            return false;
+        }
+        self.entries.push(Entry {
+            day,
+            part,
+            input,
+            answer: answer.to_owned(),
+            rejection: Some(rejection),
+        });
+        true
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut entries = self.entries.iter().collect::<Vec<_>>();
+        entries.sort_by_key(|entry| (entry.day, entry.part));
+        let mut text = String::from(HEADER);
+        for entry in entries {
+            text += &format!(
+                "{:02} {} {} {}",
+                entry.day, entry.part, entry.input, entry.answer
+            );
+            if let Some(rejection) = entry.rejection {
+                text += &format!(" {}", rejection);
+            }
+            text.push('\n');
+        }
+        fs::write(&self.path, text)
+    }
+}
+
+/// Path of the ledger checked in for `year`, at the root of the workspace for
+/// the default year and in the year's directory for others.
+pub fn default_ledger_path(year: u16) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("common should live inside the workspace")
+        .join(year_dir(year))
+        .join("answers.txt")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ledger(text: &str) -> Ledger {
+        let entries = text
+            .lines()
+            .map(|line| line.parse().unwrap())
+            .collect();
+        Ledger {
+            path: PathBuf::new(),
+            entries,
+        }
+    }
+
+    #[test]
+    fn input_ids_round_trip() {
+        let id = InputId::of("0 3 6 9 12 15\n");
+        assert_eq!(id.to_string().parse::<InputId>(), Ok(id));
+        assert_ne!(id, InputId::of("0 3 6 9 12 15"));
+    }
+
+    #[test]
+    fn verdicts() {
+        let id = InputId::of("input");
+        let ledger = ledger(&format!("01 1 {} 142", id));
+        assert_eq!(ledger.check(1, Part::One, id, Some("142")), Verdict::Match);
+        assert_eq!(
+            ledger.check(1, Part::One, id, Some("141")),
+            Verdict::Mismatch {
+                expected: String::from("142")
+            }
+        );
+        assert_eq!(
+            ledger.check(1, Part::One, id, None),
+            Verdict::Missing {
+                expected: Some(String::from("142"))
+            }
+        );
+        assert_eq!(
+            ledger.check(1, Part::Two, id, Some("281")),
+            Verdict::Unknown
+        );
+        assert_eq!(
+            ledger.check(1, Part::One, InputId::of("other"), Some("142")),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn accepted_answers_are_locked_in() {
+        let id = InputId::of("input");
+        let mut ledger = ledger("");
+        assert!(ledger.record(1, Part::One, id, "142"));
+        assert!(!ledger.record(1, Part::One, id, "141"));
+        assert_eq!(ledger.answer(1, Part::One, id), Some("142"));
+    }
+
+    #[test]
+    fn rejected_answers_are_ruled_out() {
+        let id = InputId::of("input");
+        let mut ledger = ledger(&format!(
+            "05 1 {id} 500 too-high\n05 1 {id} 100 too-low\n05 1 {id} abc wrong",
+            id = id
+        ));
+        assert_eq!(ledger.answer(5, Part::One, id), None);
+        assert_eq!(
+            ledger.rejection(5, Part::One, id, "501"),
+            Some(Rejection::TooHigh)
+        );
+        assert_eq!(
+            ledger.rejection(5, Part::One, id, "100"),
+            Some(Rejection::TooLow)
+        );
+        assert_eq!(
+            ledger.rejection(5, Part::One, id, "abc"),
+            Some(Rejection::Wrong)
+        );
+        assert_eq!(ledger.rejection(5, Part::One, id, "250"), None);
+        assert_eq!(ledger.rejection(5, Part::Two, id, "501"), None);
+        assert_eq!(
+            ledger.check(5, Part::One, id, Some("600")),
+            Verdict::Rejected {
+                rejection: Rejection::TooHigh
+            }
+        );
+
+        assert!(!ledger.record_rejection(5, Part::One, id, "500", Rejection::Wrong));
+        assert!(ledger.record(5, Part::One, id, "250"));
+        assert!(!ledger.record_rejection(5, Part::One, id, "250", Rejection::Wrong));
+        assert_eq!(ledger.answer(5, Part::One, id), Some("250"));
+    }
+
+    #[test]
+    fn malformed_entries_are_located() {
+        let error = "01 3 0000000000000000 142"
+            .parse::<Entry>()
+            .unwrap_err();
+        assert_eq!(error.to_string(), "1:4: Invalid part 3, expected 1 or 2");
+        let error = "01 1 0000000000000000 142 right"
+            .parse::<Entry>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "1:27: Invalid rejection right, expected wrong, too-high or too-low"
+        );
+    }
+}
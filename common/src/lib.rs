@@ -1,3 +1,49 @@
-pub fn read_file(file_path: &str) -> String {
-    std::fs::read_to_string(file_path).unwrap_or_else(|_| panic!("Cannot read file {}", file_path))
+mod alloc;
+mod error;
+mod explore;
+#[cfg(feature = "http")]
+mod fetch;
+mod generate;
+mod grid;
+mod input;
+mod ledger;
+mod parse;
+#[cfg(feature = "http")]
+mod server;
+mod solution;
+mod stream;
+#[cfg(feature = "http")]
+mod submit;
+#[cfg(all(any(test, feature = "test-server"), feature = "http"))]
+#[doc(hidden)]
+pub mod test_server;
+mod trace;
+
+pub use alloc::{count_allocations, AllocStats};
+pub use error::{Error, ParseError, Result};
+pub use explore::{not_found, ExploringSolution, ReplCommand, Session};
+#[cfg(feature = "http")]
+pub use fetch::{Fetched, Fetcher};
+pub use generate::{AnonymisingSolution, Generated, GeneratingSolution, Rng};
+pub use grid::{Coordinates, Grid};
+pub use input::{bundled_input_path, day_dir, input_path, year_dir, InputSource, INPUT_DIR_VAR};
+pub use ledger::{default_ledger_path, InputId, Ledger, Rejection, Verdict};
+pub use parse::{parse_lines, Span};
+#[cfg(feature = "http")]
+pub use server::{BASE_URL_VAR, DEFAULT_BASE_URL, DEFAULT_MIN_INTERVAL, SESSION_VAR};
+pub use solution::{
+    print_answers, report, Day, Outcome, Part, PartOutcome, Solution, DEFAULT_YEAR,
+};
+pub use stream::{print_streamed_answers, Line, Lines, StreamOutcome, StreamingSolution};
+#[cfg(feature = "http")]
+pub use submit::{parse_response, Response, Submitter};
+#[doc(hidden)]
+pub use trace::emit as emit_trace;
+pub use trace::{capture_trace, set_trace_level, tracing, Level, Record};
+
+pub fn read_file(file_path: &str) -> Result<String> {
+    std::fs::read_to_string(file_path).map_err(|error| Error::Io {
+        source: String::from(file_path),
+        error,
+    })
 }
@@ -0,0 +1,421 @@
+use std::{any, str::FromStr};
+
+use crate::ParseError;
+
+/// A piece of puzzle input which remembers where it sits in the text it was
+/// cut from, so that errors about it can point at a line and column.
+///
+/// Every method returns spans into the same source, so parsers compose by
+/// cutting the input down step by step, and an error raised on the smallest
+/// piece still points at the right place in the whole input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span<'a> {
+    source: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Span {
+            source,
+            text: source,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Line and column of the start of the span, both counting from 1.
+    pub fn location(&self) -> (usize, usize) {
+        let before = &self.source[..self.offset_in(self.source)];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rsplit('\n')
+            .next()
+            .map_or(0, |start_of_line| start_of_line.chars().count())
+            + 1;
+        (line, column)
+    }
+
+    /// An error located at the start of the span.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        let (line, column) = self.location();
+        let mut error = ParseError::new(message);
+        error.line = Some(line);
+        error.column = Some(column);
+        error
+    }
+
+    fn offset_in(&self, text: &str) -> usize {
+        self.text.as_ptr() as usize - text.as_ptr() as usize
+    }
+
+    fn sub(&self, text: &'a str) -> Self {
+        Span {
+            source: self.source,
+            text,
+        }
+    }
+
+    pub fn trim(&self) -> Self {
+        self.sub(self.text.trim())
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Self, ParseError> {
+        self.text
+            .strip_prefix(prefix)
+            .map(|rest| self.sub(rest))
+            .ok_or_else(|| self.error(format!("Expected `{}`", prefix)))
+    }
+
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Self, ParseError> {
+        self.text
+            .strip_suffix(suffix)
+            .map(|rest| self.sub(rest))
+            .ok_or_else(|| self.error(format!("Expected `{}` at the end", suffix)))
+    }
+
+    /// Splits a header from its body, such as `Game 1` from the rounds in
+    /// `Game 1: 3 blue, 4 red`.
+    pub fn split_once(&self, delimiter: &str) -> Result<(Self, Self), ParseError> {
+        self.text
+            .split_once(delimiter)
+            .map(|(head, tail)| (self.sub(head), self.sub(tail)))
+            .ok_or_else(|| self.error(format!("Expected `{}`", delimiter)))
+    }
+
+    /// Splits off the first word, such as a command from its arguments. The
+    /// rest is trimmed, so is empty after a lone word.
+    pub fn split_first_word(&self) -> Option<(Self, Self)> {
+        let text = self.text.trim_start();
+        let end = text
+            .find(char::is_whitespace)
+            .unwrap_or(text.len());
+        (end > 0).then(|| (self.sub(&text[..end]), self.sub(text[end..].trim())))
+    }
+
+    pub fn split<'b>(&self, delimiter: &'b str) -> impl Iterator<Item = Self> + 'b
+    where
+        'a: 'b,
+    {
+        let span = *self;
+        self.text
+            .split(delimiter)
+            .map(move |part| span.sub(part))
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = Self> {
+        let span = *self;
+        self.text
+            .split_whitespace()
+            .map(move |word| span.sub(word))
+    }
+
+    /// Exactly `N` whitespace separated words, such as the arguments of a
+    /// command.
+    pub fn split_words<const N: usize>(&self) -> Result<[Self; N], ParseError> {
+        let words = self.words().collect::<Vec<_>>();
+        let found = words.len();
+        words.try_into().map_err(|_| {
+            self.error(format!(
+                "Expected {} word{}, found {}",
+                N,
+                if N == 1 { "" } else { "s" },
+                found
+            ))
+        })
+    }
+
+    pub fn chars(&self) -> impl Iterator<Item = Self> {
+        let span = *self;
+        self.text
+            .char_indices()
+            .map(move |(i, c)| span.sub(&span.text[i..i + c.len_utf8()]))
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Self> {
+        let span = *self;
+        self.text
+            .lines()
+            .map(move |line| span.sub(line))
+    }
+
+    /// Blocks of consecutive non-blank lines, such as the maps of an almanac.
+    pub fn sections(&self) -> impl Iterator<Item = Self> {
+        let span = *self;
+        let mut lines = self.lines().peekable();
+        std::iter::from_fn(move || {
+            while lines
+                .next_if(|line| line.is_blank())
+                .is_some()
+            {}
+            let first = lines.next()?;
+            let mut last = first;
+            while let Some(line) = lines.next_if(|line| !line.is_blank()) {
+                last = line;
+            }
+            let start = first.offset_in(span.text);
+            let end = last.offset_in(span.text) + last.text.len();
+            Some(span.sub(&span.text[start..end]))
+        })
+    }
+
+    /// The body of the section whose first line is `header`.
+    pub fn section(&self, header: &str) -> Result<Self, ParseError> {
+        let section = self
+            .sections()
+            .find(|section| section.text.lines().next() == Some(header))
+            .ok_or_else(|| self.error(format!("Missing section `{}`", header)))?;
+        Ok(match section.text.split_once('\n') {
+            Some((_, body)) => section.sub(body),
+            None => section.sub(&section.text[section.text.len()..]),
+        })
+    }
+
+    fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    /// Parses the whole span as a plain value, such as a number.
+    pub fn value<T: FromStr>(&self) -> Result<T, ParseError> {
+        self.text.parse::<T>().map_err(|_| {
+            self.error(format!(
+                "Expected {}, found `{}`",
+                any::type_name::<T>(),
+                self.text
+            ))
+        })
+    }
+
+    /// Parses the span with another parser from this workspace, moving its
+    /// error to where the span sits in the whole input.
+    pub fn parse<T: FromStr<Err = ParseError>>(&self) -> Result<T, ParseError> {
+        T::from_str(self.text).map_err(|error| {
+            let (line, column) = self.location();
+            error.relative_to(line, column)
+        })
+    }
+
+    /// Parses a whitespace separated list, such as `41 48 83 86`.
+    pub fn numbers<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        self.words()
+            .map(|word| word.value())
+            .collect()
+    }
+
+    /// Parses a labelled list, such as `Time:  7  15   30`.
+    pub fn labelled_numbers<T: FromStr>(&self, label: &str) -> Result<Vec<T>, ParseError> {
+        self.labelled(label)?.numbers()
+    }
+
+    /// The text following `label:`, without surrounding whitespace.
+    pub fn labelled(&self, label: &str) -> Result<Self, ParseError> {
+        let (head, body) = self.split_once(":")?;
+        if head.text.trim() != label {
+            return Err(head.error(format!("Expected label `{}`", label)));
+        }
+        Ok(body.trim())
+    }
+}
+
+/// Parses every line of `input` with `T::from_str`, locating any failure in
+/// the whole input.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    Span::new(input)
+        .lines()
+        .map(|line| line.parse())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn located(error: ParseError) -> (Option<usize>, Option<usize>, String) {
+        (error.line, error.column, error.message)
+    }
+
+    #[test]
+    fn pieces_keep_their_location() {
+        let input = Span::new("Game 1: 3 blue\nGame 2: 4 red");
+        let second = input.lines().nth(1).unwrap();
+        let (head, tail) = second.split_once(": ").unwrap();
+        assert_eq!((head.as_str(), tail.as_str()), ("Game 2", "4 red"));
+        assert_eq!(head.location(), (2, 1));
+        assert_eq!(tail.location(), (2, 9));
+        let words = tail.words().collect::<Vec<_>>();
+        assert_eq!(words[1].location(), (2, 11));
+    }
+
+    #[test]
+    fn missing_delimiters_are_located_at_the_span() {
+        let line = Span::new("one\ntwo three")
+            .lines()
+            .nth(1)
+            .unwrap();
+        assert_eq!(
+            located(line.split_once(":").unwrap_err()),
+            (Some(2), Some(1), String::from("Expected `:`"))
+        );
+    }
+
+    #[test]
+    fn prefixes_and_suffixes_are_stripped() {
+        let span = Span::new("(AAA, BBB)");
+        let inner = span
+            .strip_prefix("(")
+            .unwrap()
+            .strip_suffix(")")
+            .unwrap();
+        assert_eq!(inner.as_str(), "AAA, BBB");
+        assert_eq!(inner.location(), (1, 2));
+        assert_eq!(
+            located(span.strip_prefix("[").unwrap_err()),
+            (Some(1), Some(1), String::from("Expected `[`"))
+        );
+        assert_eq!(
+            located(inner.strip_suffix("]").unwrap_err()),
+            (Some(1), Some(2), String::from("Expected `]` at the end"))
+        );
+    }
+
+    #[test]
+    fn sections_skip_blank_lines() {
+        let input = Span::new("\nseeds: 1\n\n\na:\n1 2\n3 4\n  \nb:\n");
+        let sections = input
+            .sections()
+            .map(|section| (section.as_str(), section.location()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sections,
+            [
+                ("seeds: 1", (2, 1)),
+                ("a:\n1 2\n3 4", (5, 1)),
+                ("b:", (9, 1))
+            ]
+        );
+    }
+
+    #[test]
+    fn sections_are_found_by_header() {
+        let input = Span::new("a:\n1 2\n\nb:\n");
+        let body = input.section("a:").unwrap();
+        assert_eq!(body.as_str(), "1 2");
+        assert_eq!(body.location(), (2, 1));
+        assert!(input.section("b:").unwrap().is_empty());
+        assert_eq!(
+            located(input.section("c:").unwrap_err()),
+            (Some(1), Some(1), String::from("Missing section `c:`"))
+        );
+    }
+
+    #[test]
+    fn numbers_are_located_by_word() {
+        let span = Span::new("41  48 x3");
+        assert_eq!(
+            span.numbers::<u32>()
+                .unwrap_err()
+                .column,
+            Some(8)
+        );
+        assert_eq!(
+            Span::new(" 41  48 ")
+                .numbers::<u32>()
+                .unwrap(),
+            [41, 48]
+        );
+        assert!(Span::new("")
+            .numbers::<u32>()
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            Span::new("300")
+                .numbers::<u8>()
+                .unwrap_err()
+                .message,
+            "Expected u8, found `300`"
+        );
+    }
+
+    #[test]
+    fn labels_are_checked() {
+        let span = Span::new("Time:  7  15   30");
+        assert_eq!(
+            span.labelled_numbers::<u32>("Time")
+                .unwrap(),
+            [7, 15, 30]
+        );
+        assert_eq!(
+            located(
+                span.labelled_numbers::<u32>("Distance")
+                    .unwrap_err()
+            ),
+            (Some(1), Some(1), String::from("Expected label `Distance`"))
+        );
+        assert_eq!(
+            located(
+                Span::new("Time 7")
+                    .labelled_numbers::<u32>("Time")
+                    .unwrap_err()
+            ),
+            (Some(1), Some(1), String::from("Expected `:`"))
+        );
+    }
+
+    #[test]
+    fn words_are_counted() {
+        let span = Span::new("walk  AAA 3");
+        let (command, args) = span.split_first_word().unwrap();
+        assert_eq!((command.as_str(), args.as_str()), ("walk", "AAA 3"));
+        let [node, steps] = args.split_words::<2>().unwrap();
+        assert_eq!((node.as_str(), steps.location()), ("AAA", (1, 11)));
+        assert_eq!(
+            located(args.split_words::<1>().unwrap_err()),
+            (Some(1), Some(7), String::from("Expected 1 word, found 2"))
+        );
+        assert_eq!(
+            args.split_words::<3>()
+                .unwrap_err()
+                .message,
+            "Expected 3 words, found 2"
+        );
+        assert_eq!(Span::new("  ").split_first_word(), None);
+    }
+
+    #[test]
+    fn inner_errors_move_to_the_span() {
+        #[derive(Debug)]
+        struct Pair;
+
+        impl FromStr for Pair {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Span::new(s)
+                    .split_once(",")?
+                    .1
+                    .value::<u32>()?;
+                Ok(Pair)
+            }
+        }
+
+        let error = parse_lines::<Pair>("1,2\n3;4\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(1)));
+        let error = Span::new("a\n  3,x")
+            .lines()
+            .nth(1)
+            .unwrap()
+            .trim()
+            .parse::<Pair>()
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(5)));
+    }
+}
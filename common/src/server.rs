@@ -0,0 +1,115 @@
+use std::{
+    env, thread,
+    time::{Duration, Instant},
+};
+
+use crate::{Error, Result};
+
+/// Environment variable holding the session cookie of a logged in account.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding the puzzle server, such as for a local
+/// stand-in.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The least time between two requests, to go easy on the server.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = "aoc runner (Rust, ureq)";
+
+/// The puzzle server, reached as a logged in account.
+pub(crate) struct Server {
+    base_url: String,
+    session: Option<String>,
+    pub(crate) min_interval: Duration,
+    last_request: Option<Instant>,
+    agent: ureq::Agent,
+}
+
+impl Server {
+    /// Without a session, every request fails before reaching the server.
+    pub(crate) fn new(base_url: &str, session: Option<&str>) -> Self {
+        Server {
+            base_url: base_url
+                .trim_end_matches('/')
+                .to_owned(),
+            session: session.map(|session| session.trim().to_owned()),
+            min_interval: DEFAULT_MIN_INTERVAL,
+            last_request: None,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Takes the session from [`SESSION_VAR`] and the base URL from
+    /// [`BASE_URL_VAR`] if it is set.
+    pub(crate) fn from_env() -> Self {
+        let session = env::var(SESSION_VAR).ok();
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| String::from(DEFAULT_BASE_URL));
+        Self::new(&base_url, session.as_deref())
+    }
+
+    /// Waits until the previous request is at least the minimum interval ago.
+    fn throttle(&mut self) {
+        if let Some(last_request) = self.last_request {
+            let elapsed = last_request.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    /// Gets `path` under the URL of `year`, or posts `form` to it, returning
+    /// the body of a successful response.
+    pub(crate) fn request(
+        &mut self,
+        year: u16,
+        path: &str,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String> {
+        let Some(session) = &self.session else {
+            return Err(Error::Http(format!(
+                "Set {} to the session cookie of your account",
+                SESSION_VAR
+            )));
+        };
+        let url = format!("{}/{}/{}", self.base_url, year, path);
+        let cookie = format!("session={}", session);
+        self.throttle();
+        let response = match form {
+            Some(form) => self
+                .agent
+                .post(&url)
+                .set("Cookie", &cookie)
+                .send_form(form),
+            None => self
+                .agent
+                .get(&url)
+                .set("Cookie", &cookie)
+                .call(),
+        };
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|error| Error::Http(format!("Cannot read {}: {}", url, error))),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response
+                    .into_string()
+                    .unwrap_or_default();
+                Err(Error::Http(format!(
+                    "{} answered {}: {}",
+                    url,
+                    status,
+                    body.lines().next().unwrap_or_default()
+                )))
+            }
+            // Transport errors already name the URL
+            Err(error) => Err(Error::Http(error.to_string())),
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use std::{
+    fmt,
+    process::ExitCode,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    count_allocations, explore, generate, stream, trace, AllocStats, AnonymisingSolution,
+    ExploringSolution, Generated, GeneratingSolution, InputSource, Result, Rng, Session,
+    StreamOutcome, StreamingSolution,
+};
+
+/// One of the two halves of a day's puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Invalid part {}, expected 1 or 2", s)),
+        }
+    }
+}
+
+/// The year of days which do not name theirs: the first event this workspace
+/// solved, whose days keep the flat layout.
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// A day's puzzle: how to parse its input and how to answer each part.
+///
+/// A part which has not been solved yet keeps the default implementation and
+/// answers `Ok(None)`.
+pub trait Solution {
+    /// The event the puzzle is from.
+    const YEAR: u16 = DEFAULT_YEAR;
+
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part_one(_input: &Self::Input) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    fn part_two(_input: &Self::Input) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    fn solve(input: &Self::Input, part: Part) -> Result<Option<String>> {
+        match part {
+            Part::One => Self::part_one(input),
+            Part::Two => Self::part_two(input),
+        }
+    }
+}
+
+/// What running a day on one input produced, and how long each phase took.
+pub struct Outcome {
+    pub parse_time: Duration,
+    /// `None` unless allocations are counted, see [`count_allocations`].
+    pub parse_allocations: Option<AllocStats>,
+    pub parts: Vec<PartOutcome>,
+}
+
+pub struct PartOutcome {
+    pub part: Part,
+    /// `Ok(None)` where the part is unsolved.
+    pub answer: Result<Option<String>>,
+    pub time: Duration,
+    pub allocations: Option<AllocStats>,
+}
+
+/// A [`Solution`] with its input type erased, so that every day can be kept
+/// in the same registry.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub number: u8,
+    run: fn(&str, &[Part]) -> Result<Outcome>,
+    stream: Option<StreamFn>,
+    generate: Option<fn(&mut Rng, usize) -> Generated>,
+    explore: Option<fn(&str) -> Result<Session>>,
+    anonymise: Option<AnonymiseFn>,
+}
+
+type StreamFn = fn(&InputSource, &[Part]) -> Result<StreamOutcome>;
+
+type AnonymiseFn = fn(&str, &mut Rng) -> Result<Generated>;
+
+impl Day {
+    pub fn of<S: Solution>() -> Self {
+        Day {
+            year: S::YEAR,
+            number: S::DAY,
+            run: run::<S>,
+            stream: None,
+            generate: None,
+            explore: None,
+            anonymise: None,
+        }
+    }
+
+    /// Lets the day stream its input, `S` being the day's solution.
+    pub fn with_streaming<S: StreamingSolution>(self) -> Self {
+        Day {
+            stream: Some(stream::stream::<S>),
+            ..self
+        }
+    }
+
+    /// Lets the day generate inputs, `S` being the day's solution.
+    pub fn with_generator<S: GeneratingSolution>(self) -> Self {
+        Day {
+            generate: Some(S::generate),
+            ..self
+        }
+    }
+
+    /// Lets the day anonymise inputs, `S` being the day's solution.
+    pub fn with_anonymiser<S: AnonymisingSolution>(self) -> Self {
+        Day {
+            anonymise: Some(generate::anonymise::<S>),
+            ..self
+        }
+    }
+
+    /// Lets the day's parsed input be explored, `S` being the day's solution.
+    pub fn with_explorer<S: ExploringSolution>(self) -> Self
+    where
+        S::Input: 'static,
+    {
+        Day {
+            explore: Some(explore::session::<S>),
+            ..self
+        }
+    }
+
+    /// Parses `input` once and answers each of the requested parts.
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<Outcome> {
+        (self.run)(input, parts)
+    }
+
+    /// Answers the requested parts in one pass over the lines of `source`, or
+    /// gives `None` if the day cannot stream its input.
+    pub fn stream(&self, source: &InputSource, parts: &[Part]) -> Option<Result<StreamOutcome>> {
+        self.stream
+            .map(|stream| stream(source, parts))
+    }
+
+    /// Generates an input of about `size`, or gives `None` if the day has no
+    /// generator.
+    pub fn generate(&self, rng: &mut Rng, size: usize) -> Option<Generated> {
+        self.generate
+            .map(|generate| generate(rng, size))
+    }
+
+    /// Rewrites `input` into an input of the same shape, with the answers it
+    /// gives, or gives `None` if the day cannot anonymise inputs.
+    pub fn anonymise(&self, input: &str, rng: &mut Rng) -> Option<Result<Generated>> {
+        self.anonymise
+            .map(|anonymise| anonymise(input, rng))
+    }
+
+    /// Parses `input` to run commands against, or gives `None` if the day has
+    /// no commands.
+    pub fn explore(&self, input: &str) -> Option<Result<Session>> {
+        self.explore
+            .map(|explore| explore(input))
+    }
+}
+
+fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<Outcome> {
+    trace::enter(S::DAY, None);
+    // Timed within the count, so that counting is not timed
+    let ((parsed, parse_time), parse_allocations) = count_allocations(|| {
+        let start = Instant::now();
+        (S::parse(input), start.elapsed())
+    });
+    let parsed = parsed?;
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            trace::enter(S::DAY, Some(part));
+            let ((answer, time), allocations) = count_allocations(|| {
+                let start = Instant::now();
+                (S::solve(&parsed, part), start.elapsed())
+            });
+            PartOutcome {
+                part,
+                answer,
+                time,
+                allocations,
+            }
+        })
+        .collect();
+    Ok(Outcome {
+        parse_time,
+        parse_allocations,
+        parts,
+    })
+}
+
+/// Prints the answer of every solved part on its own line, for the
+/// single-day binaries. Stops at the first part that fails.
+pub fn print_answers<S: Solution>(input: &str) -> Result<()> {
+    for outcome in run::<S>(input, &Part::ALL)?.parts {
+        if let Some(answer) = outcome.answer? {
+            println!("{}", answer);
+        }
+    }
+    Ok(())
+}
+
+/// Turns the outcome of a binary into its exit code, printing any error.
+pub fn report(result: Result<()>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
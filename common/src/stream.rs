@@ -0,0 +1,154 @@
+use std::{
+    fmt,
+    io::BufRead,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{trace, Error, InputSource, ParseError, Part, Result, Solution, Span};
+
+/// One line of an input read as a stream, without its line ending.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Line {
+    /// Counting from 1.
+    pub number: usize,
+    pub text: String,
+}
+
+impl Line {
+    /// A span over the line. Errors raised on it are relative to the line,
+    /// and [`Line::locate`] moves them to where the line sits in the input.
+    pub fn span(&self) -> Span<'_> {
+        Span::new(&self.text)
+    }
+
+    pub fn locate(&self, error: ParseError) -> ParseError {
+        error.relative_to(self.number, 1)
+    }
+
+    /// Parses the whole line, locating any failure in the input.
+    pub fn parse<T: FromStr<Err = ParseError>>(&self) -> std::result::Result<T, ParseError> {
+        self.text
+            .parse()
+            .map_err(|error| self.locate(error))
+    }
+}
+
+/// Reads an input one line at a time, so that only the current line is held
+/// in memory.
+pub struct Lines<R> {
+    reader: R,
+    source: String,
+    number: usize,
+}
+
+impl<R: BufRead> Lines<R> {
+    /// Reads lines from `reader`, naming `source` in read errors.
+    pub fn new(reader: R, source: impl fmt::Display) -> Self {
+        Lines {
+            reader,
+            source: source.to_string(),
+            number: 0,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = Result<Line>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut text = String::new();
+        match self.reader.read_line(&mut text) {
+            Ok(0) => None,
+            Ok(_) => {
+                if text.ends_with('\n') {
+                    text.pop();
+                    if text.ends_with('\r') {
+                        text.pop();
+                    }
+                }
+                self.number += 1;
+                Some(Ok(Line {
+                    number: self.number,
+                    text,
+                }))
+            }
+            Err(error) => Some(Err(Error::Io {
+                source: self.source.clone(),
+                error,
+            })),
+        }
+    }
+}
+
+/// A [`Solution`] which can also answer from a stream of lines, for inputs
+/// too large to hold in memory.
+pub trait StreamingSolution: Solution {
+    /// Answers both parts in a single pass over `lines`, giving `None` for a
+    /// part which is unsolved.
+    fn solve_lines(lines: &mut dyn Iterator<Item = Result<Line>>) -> Result<[Option<String>; 2]>;
+}
+
+/// What streaming an input through a day produced. Both parts are answered
+/// in the same pass, so there is one time for reading and solving.
+pub struct StreamOutcome {
+    pub answers: Vec<(Part, Option<String>)>,
+    pub time: Duration,
+}
+
+pub(crate) fn stream<S: StreamingSolution>(
+    source: &InputSource,
+    parts: &[Part],
+) -> Result<StreamOutcome> {
+    trace::enter(S::DAY, None);
+    let start = Instant::now();
+    let [part_one, part_two] = S::solve_lines(&mut source.lines()?)?;
+    let time = start.elapsed();
+    let answers = parts
+        .iter()
+        .map(|&part| match part {
+            Part::One => (part, part_one.clone()),
+            Part::Two => (part, part_two.clone()),
+        })
+        .collect();
+    Ok(StreamOutcome { answers, time })
+}
+
+/// Like [`crate::print_answers`], streaming the input from `source` rather
+/// than reading it whole.
+pub fn print_streamed_answers<S: StreamingSolution>(source: &InputSource) -> Result<()> {
+    for (_, answer) in stream::<S>(source, &Part::ALL)?.answers {
+        if let Some(answer) = answer {
+            println!("{}", answer);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_drop_their_endings() {
+        let lines = Lines::new("one\r\ntwo\n\nthree".as_bytes(), "test")
+            .map(|line| line.unwrap().text)
+            .collect::<Vec<_>>();
+        assert_eq!(lines, ["one", "two", "", "three"]);
+    }
+
+    #[test]
+    fn errors_are_located_on_their_line() {
+        let line = Lines::new("1\n2 x\n".as_bytes(), "test")
+            .nth(1)
+            .unwrap()
+            .unwrap();
+        let error = line
+            .span()
+            .numbers::<u32>()
+            .map_err(|error| line.locate(error))
+            .unwrap_err();
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.column, Some(3));
+    }
+}
@@ -0,0 +1,239 @@
+use std::time::Duration;
+
+use crate::{server::Server, Part, Rejection, Result};
+
+/// What the puzzle server made of a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Response {
+    Right,
+    /// The answer is wrong. The server asks for a wait before the next try.
+    Wrong {
+        rejection: Rejection,
+        wait: Option<Duration>,
+    },
+    /// An answer was submitted too recently, so this one was not checked.
+    TooSoon {
+        wait: Option<Duration>,
+    },
+    /// The part is solved already, or needs the part before it solved first.
+    WrongLevel,
+    /// A page this client does not understand, as plain text.
+    Unrecognised(String),
+}
+
+/// The text of the page's `<article>`, or of the whole page without one,
+/// with tags removed and whitespace collapsed.
+fn article_text(html: &str) -> String {
+    // Cutting at `<article` leaves the rest of its opening tag to skip
+    let (article, mut in_tag) = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or((html, false), |(article, _)| (article, true));
+    let mut text = String::new();
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Reads a wait such as `1m 23s` or `45s`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    s.split_whitespace()
+        .try_fold(0u64, |total, amount| {
+            let seconds = [("h", 3600), ("m", 60), ("s", 1)]
+                .into_iter()
+                .find_map(|(unit, seconds)| {
+                    let number = amount.strip_suffix(unit)?;
+                    number
+                        .parse::<u64>()
+                        .ok()?
+                        .checked_mul(seconds)
+                })?;
+            total.checked_add(seconds)
+        })
+        .map(Duration::from_secs)
+}
+
+/// Reads the wait after a wrong answer, such as `wait one minute` or `wait 5
+/// minutes`.
+fn parse_wait_after_wrong(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("wait ")?;
+    let mut words = rest.split_whitespace();
+    let minutes: u64 = match words.next()? {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+    if !words.next()?.starts_with("minute") {
+        return None;
+    }
+    minutes
+        .checked_mul(60)
+        .map(Duration::from_secs)
+}
+
+/// Reads the page the server answers a submission with.
+pub fn parse_response(html: &str) -> Response {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Response::Right
+    } else if text.contains("That's not the right answer") {
+        let rejection = if text.contains("too high") {
+            Rejection::TooHigh
+        } else if text.contains("too low") {
+            Rejection::TooLow
+        } else {
+            Rejection::Wrong
+        };
+        Response::Wrong {
+            rejection,
+            wait: parse_wait_after_wrong(&text),
+        }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_duration(wait));
+        Response::TooSoon { wait }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Response::WrongLevel
+    } else {
+        Response::Unrecognised(text)
+    }
+}
+
+/// Posts answers to the puzzle server.
+pub struct Submitter {
+    server: Server,
+}
+
+impl Submitter {
+    /// Without a session, every submission fails before reaching the server.
+    pub fn new(base_url: &str, session: Option<&str>) -> Self {
+        Submitter {
+            server: Server::new(base_url, session),
+        }
+    }
+
+    /// Takes the session from [`crate::SESSION_VAR`] and the base URL from
+    /// [`crate::BASE_URL_VAR`] if it is set.
+    pub fn from_env() -> Self {
+        Submitter {
+            server: Server::from_env(),
+        }
+    }
+
+    pub fn submit(&mut self, year: u16, day: u8, part: Part, answer: &str) -> Result<Response> {
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        let page = self
+            .server
+            .request(year, &format!("day/{}/answer", day), Some(&form))?;
+        Ok(parse_response(&page))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{response, serve};
+
+    fn page(article: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn responses_are_recognised() {
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."
+            )),
+            Response::Right
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again. <a href=\"/2023/day/5\">[Return to Day 5]</a>"
+            )),
+            Response::Wrong {
+                rejection: Rejection::TooHigh,
+                wait: Some(Duration::from_secs(60)),
+            }
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer.  If you're stuck, ... please wait 5 minutes before trying again."
+            )),
+            Response::Wrong {
+                rejection: Rejection::Wrong,
+                wait: Some(Duration::from_secs(300)),
+            }
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href=\"/2023/day/5\">[Return to Day 5]</a>"
+            )),
+            Response::TooSoon {
+                wait: Some(Duration::from_secs(83)),
+            }
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Response::WrongLevel
+        );
+        assert_eq!(
+            parse_response("<p>Server   busy</p>"),
+            Response::Unrecognised(String::from("Server busy"))
+        );
+    }
+
+    #[test]
+    fn waits_are_read_from_any_text() {
+        assert_eq!(parse_duration("1h 2m 3s"), Some(Duration::from_secs(3723)));
+        assert_eq!(parse_duration("23é"), None);
+        assert_eq!(parse_duration("5 s"), None);
+        assert_eq!(parse_duration("18446744073709551615h"), None);
+        assert_eq!(
+            parse_wait_after_wrong("Please wait 18446744073709551615 minutes"),
+            None
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You gave an answer too recently.  You have 1m 2é left to wait."
+            )),
+            Response::TooSoon { wait: None }
+        );
+    }
+
+    #[test]
+    fn answers_are_posted_as_forms() {
+        let body = page("That's not the right answer; your answer is too low.");
+        let (address, server) = serve(vec![response("200 OK", &body)]);
+        let mut submitter = Submitter::new(&address, Some("secret"));
+
+        assert_eq!(
+            submitter
+                .submit(2023, 5, Part::Two, "46")
+                .unwrap(),
+            Response::Wrong {
+                rejection: Rejection::TooLow,
+                wait: None,
+            }
+        );
+        let requests = server.join().unwrap();
+        assert!(requests[0]
+            .head
+            .starts_with("POST /2023/day/5/answer HTTP/1.1\r\n"));
+        assert_eq!(requests[0].body, "level=2&answer=46");
+    }
+}
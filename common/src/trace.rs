@@ -0,0 +1,179 @@
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    str::FromStr,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use crate::Part;
+
+/// How much detail a trace line carries, from least to most.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// A summary of each part, such as how many items went into it.
+    Info = 1,
+    /// How each item of the input was treated, such as one line per hand.
+    Debug,
+    /// The steps taken within each item.
+    Trace,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        })
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!(
+                "Invalid level {}, expected info, debug or trace",
+                s
+            )),
+        }
+    }
+}
+
+/// One line of a trace, with where in the run it was emitted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub level: Level,
+    pub day: u8,
+    /// `None` while parsing, or while streaming both parts at once.
+    pub part: Option<Part>,
+    pub message: String,
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<5} day {}", self.level, self.day)?;
+        match self.part {
+            Some(part) => write!(f, " part {}", part)?,
+            None => write!(f, "       ")?,
+        }
+        write!(f, " | {}", self.message)
+    }
+}
+
+/// The most detailed level printed, or 0 when tracing is off.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+thread_local! {
+    static SCOPE: Cell<(u8, Option<Part>)> = const { Cell::new((0, None)) };
+    /// Records captured on this thread instead of printed, and up to which
+    /// level.
+    static CAPTURED: RefCell<Option<(Level, Vec<Record>)>> = const { RefCell::new(None) };
+}
+
+/// Prints trace lines up to `level` on stderr from now on, or none for
+/// `None`.
+pub fn set_trace_level(level: Option<Level>) {
+    MAX_LEVEL.store(level.map_or(0, |level| level as u8), Ordering::Relaxed);
+}
+
+/// Whether lines at `level` go anywhere, so that solvers can skip working out
+/// what only a trace would show.
+pub fn tracing(level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+        || CAPTURED.with(|captured| {
+            captured
+                .borrow()
+                .as_ref()
+                .is_some_and(|&(max, _)| level <= max)
+        })
+}
+
+/// Attributes trace lines emitted on this thread to `day` and `part`.
+pub(crate) fn enter(day: u8, part: Option<Part>) {
+    SCOPE.with(|scope| scope.set((day, part)));
+}
+
+/// Emits a trace line. Use [`crate::trace!`], which skips formatting the line
+/// when its level is off.
+#[doc(hidden)]
+pub fn emit(level: Level, message: fmt::Arguments<'_>) {
+    let (day, part) = SCOPE.with(Cell::get);
+    let record = Record {
+        level,
+        day,
+        part,
+        message: message.to_string(),
+    };
+    let record = CAPTURED.with(|captured| match captured.borrow_mut().as_mut() {
+        Some((max, records)) if level <= *max => {
+            records.push(record);
+            None
+        }
+        _ => Some(record),
+    });
+    if let Some(record) = record {
+        if level as u8 <= MAX_LEVEL.load(Ordering::Relaxed) {
+            eprintln!("{}", record);
+        }
+    }
+}
+
+/// Runs `f`, collecting the trace lines it emits on this thread up to `level`
+/// rather than printing them.
+pub fn capture_trace<T>(level: Level, f: impl FnOnce() -> T) -> (T, Vec<Record>) {
+    let outer = CAPTURED.with(|captured| captured.replace(Some((level, vec![]))));
+    let result = f();
+    let records = CAPTURED.with(|captured| captured.replace(outer));
+    (result, records.map_or(vec![], |(_, records)| records))
+}
+
+/// Emits a trace line at a [`Level`], formatted like [`format!`] only if the
+/// level is traced.
+#[macro_export]
+macro_rules! trace {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::tracing($level) {
+            $crate::emit_trace($level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Unformattable;
+
+    impl fmt::Display for Unformattable {
+        fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+            panic!("Lines at levels which are off should not be formatted")
+        }
+    }
+
+    #[test]
+    fn captures_up_to_the_level() {
+        let ((), records) = capture_trace(Level::Debug, || {
+            enter(6, Some(Part::Two));
+            crate::trace!(Level::Info, "{} races", 1);
+            crate::trace!(Level::Debug, "race {}", 1);
+            crate::trace!(Level::Trace, "{}", Unformattable);
+        });
+        let records = records
+            .iter()
+            .map(Record::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            records,
+            [
+                "info  day 6 part 2 | 1 races",
+                "debug day 6 part 2 | race 1"
+            ]
+        );
+        assert!(!tracing(Level::Info));
+    }
+}
@@ -0,0 +1,78 @@
+use common::{Generated, GeneratingSolution, Rng};
+
+use crate::Day01;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// None of these letters appear in a digit's name, so filler cannot spell one
+const FILLER: &[u8] = b"abcdjklmpqyz";
+
+/// A digit written on a line, either as itself or spelled out.
+struct Token {
+    digit: u32,
+    spelled: bool,
+}
+
+impl GeneratingSolution for Day01 {
+    /// Generates `size` calibration lines, each holding at least one digit.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut input = String::new();
+        let mut sum = 0;
+        let mut sum_2 = 0;
+        for _ in 0..size {
+            let mut tokens = (0..rng.between(1..=6))
+                .map(|_| Token {
+                    digit: rng.between(1..=9) as u32,
+                    spelled: rng.chance(1, 2),
+                })
+                .collect::<Vec<_>>();
+            let plain = rng.index(tokens.len());
+            tokens[plain].spelled = false;
+
+            let digits = tokens
+                .iter()
+                .filter(|token| !token.spelled)
+                .map(|token| token.digit)
+                .collect::<Vec<_>>();
+            sum += 10 * digits[0] + digits[digits.len() - 1];
+            sum_2 += 10 * tokens[0].digit + tokens[tokens.len() - 1].digit;
+
+            // Filler between every token keeps spelled digits from overlapping
+            for token in &tokens {
+                for _ in 0..rng.between(0..=3) {
+                    input.push(*rng.pick(FILLER) as char);
+                }
+                if token.spelled {
+                    input += WORDS[token.digit as usize - 1];
+                } else {
+                    input += &token.digit.to_string();
+                }
+                input.push(*rng.pick(FILLER) as char);
+            }
+            input.push('\n');
+        }
+        Generated {
+            input,
+            answers: [Some(sum.to_string()), Some(sum_2.to_string())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    #[test]
+    fn generated_answers_match() {
+        for seed in 0..20 {
+            let generated = Day01::generate(&mut Rng::new(seed), 50);
+            let input = Day01::parse(&generated.input).unwrap();
+            assert_eq!(Day01::part_one(&input).unwrap(), generated.answers[0]);
+            assert_eq!(Day01::part_two(&input).unwrap(), generated.answers[1]);
+        }
+    }
+}
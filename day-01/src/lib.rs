@@ -0,0 +1,215 @@
+//! Day 1: Trebuchet?!
+
+mod generate;
+
+use common::{trace, Error, Level, Line, Solution, StreamingSolution};
+
+/// The calibration value of a line: its first and last digits as a two digit
+/// number.
+pub fn get_number_from_line(line: &str) -> Option<u32> {
+    let mut digits = line
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .peekable();
+    let first_digit = *digits.peek()?;
+    let last_digit = digits.last()?;
+    Some(10 * first_digit + last_digit)
+}
+
+/// Replaces every spelled out digit with the digit itself. Words may overlap,
+/// so `eightwo` becomes `8wo` followed by `2`.
+pub fn replace_words_with_digits(word: &str) -> String {
+    if word.len() < 3 {
+        return String::from(word);
+    }
+    let replacement = {
+        if word.starts_with("one") {
+            Some("1")
+        } else if word.starts_with("two") {
+            Some("2")
+        } else if word.starts_with("three") {
+            Some("3")
+        } else if word.starts_with("four") {
+            Some("4")
+        } else if word.starts_with("five") {
+            Some("5")
+        } else if word.starts_with("six") {
+            Some("6")
+        } else if word.starts_with("seven") {
+            Some("7")
+        } else if word.starts_with("eight") {
+            Some("8")
+        } else if word.starts_with("nine") {
+            Some("9")
+        } else {
+            None
+        }
+    };
+    match replacement {
+        Some(replacement) => replacement.to_owned() + &replace_words_with_digits(&word[1..]),
+        None => word[0..1].to_owned() + &replace_words_with_digits(&word[1..]),
+    }
+}
+
+/// Like [`get_number_from_line`], counting spelled out digits too.
+pub fn get_number_from_line_2(line: &str) -> Option<u32> {
+    let replaced = replace_words_with_digits(line);
+    trace!(Level::Trace, "{} reads as {}", line, replaced);
+    let mut digits = replaced
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .peekable();
+    let first_digit = *digits.peek()?;
+    let last_digit = digits.last()?;
+    Some(10 * first_digit + last_digit)
+}
+
+/// Adds up as a line's calibration value, tracing how the line was read.
+fn traced_value(line: &str, value: Option<u32>) -> u32 {
+    match value {
+        Some(value) => trace!(Level::Debug, "{} has calibration value {}", line, value),
+        None => trace!(Level::Debug, "{} has no digits, counting 0", line),
+    }
+    value.unwrap_or(0)
+}
+
+/// Adds a calibration value to a running total, which only overflows on
+/// inputs far larger than any that fit in memory.
+fn add_value(sum: u64, value: u32) -> common::Result<u64> {
+    sum.checked_add(u64::from(value))
+        .ok_or_else(|| Error::Domain(String::from("Calibration values overflow")))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = String;
+
+    fn parse(input: &str) -> common::Result<Self::Input> {
+        Ok(input.trim().to_owned())
+    }
+
+    fn part_one(input: &Self::Input) -> common::Result<Option<String>> {
+        let sum = input
+            .lines()
+            .map(|line| traced_value(line, get_number_from_line(line)))
+            .try_fold(0, add_value)?;
+        Ok(Some(sum.to_string()))
+    }
+
+    fn part_two(input: &Self::Input) -> common::Result<Option<String>> {
+        let sum_2 = input
+            .lines()
+            .map(|line| traced_value(line, get_number_from_line_2(line)))
+            .try_fold(0, add_value)?;
+        Ok(Some(sum_2.to_string()))
+    }
+}
+
+impl StreamingSolution for Day01 {
+    fn solve_lines(
+        lines: &mut dyn Iterator<Item = common::Result<Line>>,
+    ) -> common::Result<[Option<String>; 2]> {
+        let mut sum = 0;
+        let mut sum_2 = 0;
+        for line in lines {
+            let line = line?;
+            sum = add_value(
+                sum,
+                traced_value(&line.text, get_number_from_line(&line.text)),
+            )?;
+            sum_2 = add_value(
+                sum_2,
+                traced_value(&line.text, get_number_from_line_2(&line.text)),
+            )?;
+        }
+        Ok([Some(sum.to_string()), Some(sum_2.to_string())])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Lines;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example-2.txt");
+
+    #[test]
+    fn calibration_values_from_digits() {
+        let values = EXAMPLE
+            .lines()
+            .map(get_number_from_line)
+            .collect::<Vec<_>>();
+        assert_eq!(values, [Some(12), Some(38), Some(15), Some(77)]);
+    }
+
+    #[test]
+    fn calibration_values_from_spelled_digits() {
+        let values = EXAMPLE_2
+            .lines()
+            .map(get_number_from_line_2)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            [
+                Some(29),
+                Some(83),
+                Some(13),
+                Some(24),
+                Some(42),
+                Some(14),
+                Some(76)
+            ]
+        );
+    }
+
+    #[test]
+    fn example_part_one() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part_one(&input).unwrap(), Some(String::from("142")));
+    }
+
+    #[test]
+    fn example_part_two() {
+        let input = Day01::parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day01::part_two(&input).unwrap(), Some(String::from("281")));
+    }
+
+    #[test]
+    fn streamed_examples() {
+        let [part_one, _] =
+            Day01::solve_lines(&mut Lines::new(EXAMPLE.as_bytes(), "example")).unwrap();
+        assert_eq!(part_one, Some(String::from("142")));
+        let [_, part_two] =
+            Day01::solve_lines(&mut Lines::new(EXAMPLE_2.as_bytes(), "example")).unwrap();
+        assert_eq!(part_two, Some(String::from("281")));
+    }
+
+    #[test]
+    fn sums_pass_u32() {
+        assert_eq!(add_value(u64::from(u32::MAX), 99).unwrap(), 4294967394);
+        assert!(add_value(u64::MAX, 1).is_err());
+    }
+
+    #[test]
+    #[ignore = "streams 43 million lines, taking half a minute in debug builds"]
+    fn streamed_sums_pass_u32() {
+        // Just enough lines worth 99 each to pass u32::MAX
+        let count = u32::MAX as usize / 99 + 1;
+        let mut lines = (1..=count).map(|number| {
+            Ok(Line {
+                number,
+                text: String::from("9"),
+            })
+        });
+        let sum = (count as u64 * 99).to_string();
+        assert_eq!(
+            Day01::solve_lines(&mut lines).unwrap(),
+            [Some(sum.clone()), Some(sum)]
+        );
+    }
+}
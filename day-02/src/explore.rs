@@ -0,0 +1,156 @@
+use common::{not_found, ExploringSolution, ReplCommand, Result, Span};
+
+use crate::{Colour, CubeSet, Day02, Game, REAL_AMOUNTS};
+
+fn list_ids<'a>(games: impl Iterator<Item = &'a Game>) -> Vec<String> {
+    games
+        .map(|game| game.id.to_string())
+        .collect()
+}
+
+fn game(games: &[Game], args: Span<'_>) -> Result<String> {
+    let [id] = args.split_words()?;
+    let id = id.value::<u32>()?;
+    let game = games
+        .iter()
+        .find(|game| game.id == id)
+        .ok_or_else(|| not_found(format_args!("game {}", id)))?;
+    let rounds = game
+        .rounds
+        .iter()
+        .map(CubeSet::to_string)
+        .collect::<Vec<_>>();
+    let minimum = game.get_minimum_set();
+    Ok(format!(
+        "Game {}: {}\nNeeds at least {}, a power of {}, and is {} with the bag of part 1",
+        id,
+        rounds.join("; "),
+        minimum,
+        minimum.power()?,
+        if game.is_possible(&REAL_AMOUNTS) {
+            "possible"
+        } else {
+            "impossible"
+        }
+    ))
+}
+
+fn above(games: &[Game], args: Span<'_>) -> Result<String> {
+    let [amount, colour] = args.split_words()?;
+    let amount = amount.value::<u32>()?;
+    let colour = colour.parse::<Colour>()?;
+    let ids = list_ids(games.iter().filter(|game| {
+        game.rounds
+            .iter()
+            .any(|round| round.amount(&colour) > amount)
+    }));
+    Ok(match ids.len() {
+        0 => format!("No game shows more than {} {}", amount, colour),
+        _ => format!(
+            "{} games show more than {} {}: {}",
+            ids.len(),
+            amount,
+            colour,
+            ids.join(", ")
+        ),
+    })
+}
+
+fn possible(games: &[Game], args: Span<'_>) -> Result<String> {
+    let bag = if args.is_empty() {
+        REAL_AMOUNTS
+    } else {
+        let [red, green, blue] = args.split_words()?;
+        CubeSet {
+            red: red.value()?,
+            green: green.value()?,
+            blue: blue.value()?,
+        }
+    };
+    let ids = list_ids(
+        games
+            .iter()
+            .filter(|game| game.is_possible(&bag)),
+    );
+    Ok(format!(
+        "{} games are possible with {}: {}",
+        ids.len(),
+        bag,
+        ids.join(", ")
+    ))
+}
+
+impl ExploringSolution for Day02 {
+    const COMMANDS: &'static [ReplCommand] = &[
+        ReplCommand {
+            name: "game",
+            args: "<id>",
+            help: "Show the rounds of a game and the fewest cubes it needs",
+        },
+        ReplCommand {
+            name: "above",
+            args: "<amount> <colour>",
+            help: "List the games showing more than an amount of a colour at once",
+        },
+        ReplCommand {
+            name: "possible",
+            args: "[<red> <green> <blue>]",
+            help: "List the games possible with a bag, by default that of part 1",
+        },
+    ];
+
+    fn explore(games: &Self::Input, command: &str, args: Span<'_>) -> Option<Result<String>> {
+        match command {
+            "game" => Some(game(games, args)),
+            "above" => Some(above(games, args)),
+            "possible" => Some(possible(games, args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn run(line: &str) -> String {
+        let games = Day02::parse(EXAMPLE).unwrap();
+        let (command, args) = Span::new(line)
+            .split_first_word()
+            .unwrap();
+        Day02::explore(&games, command.as_str(), args)
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn games_above_an_amount() {
+        assert_eq!(run("above 13 red"), "2 games show more than 13 red: 3, 4");
+        assert_eq!(run("above 20 red"), "No game shows more than 20 red");
+    }
+
+    #[test]
+    fn possible_games() {
+        assert_eq!(
+            run("possible"),
+            "3 games are possible with 12 red, 13 green, 14 blue: 1, 2, 5"
+        );
+        assert_eq!(
+            run("possible 4 3 6"),
+            "2 games are possible with 4 red, 3 green, 6 blue: 1, 2"
+        );
+    }
+
+    #[test]
+    fn game_details() {
+        assert_eq!(
+            run("game 1"),
+            "Game 1: 4 red, 0 green, 3 blue; 1 red, 2 green, 6 blue; 0 red, 2 green, 0 blue\n\
+             Needs at least 4 red, 2 green, 6 blue, a power of 48, and is possible with the bag of part 1"
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use common::{Generated, GeneratingSolution, Rng};
+
+use crate::{CubeSet, Day02, REAL_AMOUNTS};
+
+impl GeneratingSolution for Day02 {
+    /// Generates `size` games, about half of them possible with the bag of
+    /// part 1.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut input = String::new();
+        let mut id_sum = 0;
+        let mut power_sum = 0;
+        for id in 1..=size {
+            // Drawing up to 20 of a colour makes some games impossible
+            let limit = if rng.chance(1, 2) { 12 } else { 20 };
+            let rounds = (0..rng.between(1..=6))
+                .map(|_| {
+                    let mut round = CubeSet {
+                        red: rng.between(0..=limit) as u32,
+                        green: rng.between(0..=limit) as u32,
+                        blue: rng.between(0..=limit) as u32,
+                    };
+                    // Every reveal shows at least one cube
+                    if round.red + round.green + round.blue == 0 {
+                        round.red = 1;
+                    }
+                    round
+                })
+                .collect::<Vec<_>>();
+
+            let most = |colour: fn(&CubeSet) -> u32| {
+                rounds
+                    .iter()
+                    .map(colour)
+                    .max()
+                    .unwrap_or(0)
+            };
+            let (red, green, blue) = (
+                most(|set| set.red),
+                most(|set| set.green),
+                most(|set| set.blue),
+            );
+            if red <= REAL_AMOUNTS.red && green <= REAL_AMOUNTS.green && blue <= REAL_AMOUNTS.blue {
+                id_sum += id;
+            }
+            power_sum += red * green * blue;
+
+            let rounds = rounds
+                .iter()
+                .map(|round| {
+                    let mut reveals = [
+                        ("red", round.red),
+                        ("green", round.green),
+                        ("blue", round.blue),
+                    ]
+                    .into_iter()
+                    .filter(|&(_, amount)| amount > 0)
+                    .map(|(colour, amount)| format!("{} {}", amount, colour))
+                    .collect::<Vec<_>>();
+                    rng.shuffle(&mut reveals);
+                    reveals.join(", ")
+                })
+                .collect::<Vec<_>>();
+            input += &format!("Game {}: {}\n", id, rounds.join("; "));
+        }
+        Generated {
+            input,
+            answers: [Some(id_sum.to_string()), Some(power_sum.to_string())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    #[test]
+    fn generated_answers_match() {
+        for seed in 0..20 {
+            let generated = Day02::generate(&mut Rng::new(seed), 50);
+            let input = Day02::parse(&generated.input).unwrap();
+            assert_eq!(Day02::part_one(&input).unwrap(), generated.answers[0]);
+            assert_eq!(Day02::part_two(&input).unwrap(), generated.answers[1]);
+        }
+    }
+}
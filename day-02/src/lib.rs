@@ -0,0 +1,305 @@
+//! Day 2: Cube Conundrum
+
+mod explore;
+mod generate;
+
+use std::cmp::max;
+use std::fmt;
+use std::str::FromStr;
+
+use common::{trace, Error, Level, ParseError, Solution, Span};
+
+pub enum Colour {
+    Red,
+    Green,
+    Blue,
+}
+
+impl FromStr for Colour {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "red" => Ok(Self::Red),
+            "green" => Ok(Self::Green),
+            "blue" => Ok(Self::Blue),
+            _ => Err(Span::new(s).error(format!("Invalid colour {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for Colour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Colour::Red => "red",
+            Colour::Green => "green",
+            Colour::Blue => "blue",
+        })
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct CubeSet {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl CubeSet {
+    /// The cubes of both sets, or `None` if a colour overflows.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(CubeSet {
+            red: self.red.checked_add(other.red)?,
+            green: self.green.checked_add(other.green)?,
+            blue: self.blue.checked_add(other.blue)?,
+        })
+    }
+
+    pub fn is_possible(&self, real_amounts: &Self) -> bool {
+        self.red <= real_amounts.red
+            && self.blue <= real_amounts.blue
+            && self.green <= real_amounts.green
+    }
+
+    /// The larger amount of each colour.
+    pub fn maximum(&self, other: &Self) -> Self {
+        CubeSet {
+            red: max(self.red, other.red),
+            green: max(self.green, other.green),
+            blue: max(self.blue, other.blue),
+        }
+    }
+
+    pub fn amount(&self, colour: &Colour) -> u32 {
+        match colour {
+            Colour::Red => self.red,
+            Colour::Green => self.green,
+            Colour::Blue => self.blue,
+        }
+    }
+
+    pub fn power(&self) -> common::Result<u32> {
+        self.red
+            .checked_mul(self.green)
+            .and_then(|power| power.checked_mul(self.blue))
+            .ok_or_else(|| Error::Domain(format!("The power of {} overflows", self)))
+    }
+}
+
+impl fmt::Display for CubeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} red, {} green, {} blue",
+            self.red, self.green, self.blue
+        )
+    }
+}
+
+impl FromStr for CubeSet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (amount, colour) = Span::new(s).split_once(" ")?;
+        let parsed_amount = amount.value()?;
+        let parsed_color = colour.parse::<Colour>()?;
+        Ok(match parsed_color {
+            Colour::Red => CubeSet {
+                red: parsed_amount,
+                green: 0,
+                blue: 0,
+            },
+            Colour::Green => CubeSet {
+                red: 0,
+                green: parsed_amount,
+                blue: 0,
+            },
+            Colour::Blue => CubeSet {
+                red: 0,
+                green: 0,
+                blue: parsed_amount,
+            },
+        })
+    }
+}
+
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<CubeSet>,
+}
+
+impl Game {
+    /// Whether every round could be drawn from a bag holding `real_amounts`.
+    pub fn is_possible(&self, real_amounts: &CubeSet) -> bool {
+        self.rounds
+            .iter()
+            .all(|reveal| reveal.is_possible(real_amounts))
+    }
+
+    /// The fewest cubes of each colour which make every round possible.
+    pub fn get_minimum_set(&self) -> CubeSet {
+        self.rounds
+            .iter()
+            .copied()
+            .reduce(|acc, e| acc.maximum(&e))
+            .unwrap()
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (header, rounds_str) = Span::new(s).split_once(": ")?;
+        let id = header.strip_prefix("Game ")?.value()?;
+        let mut rounds = vec![];
+        for round_str in rounds_str.split("; ") {
+            let mut reveal = CubeSet::default();
+            for part in round_str.split(", ") {
+                reveal = reveal
+                    .checked_add(&part.parse()?)
+                    .ok_or_else(|| part.error("Too many cubes in one round"))?;
+            }
+            rounds.push(reveal);
+        }
+        Ok(Game { id, rounds })
+    }
+}
+
+/// The bag the elf asks about in part 1.
+pub const REAL_AMOUNTS: CubeSet = CubeSet {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+pub fn sum_possible_game_ids(games: &[Game], real_amounts: &CubeSet) -> common::Result<u32> {
+    let possible = games
+        .iter()
+        .filter(|game| {
+            let possible = game.is_possible(real_amounts);
+            trace!(
+                Level::Debug,
+                "Game {} needs at least {}, so it is {}",
+                game.id,
+                game.get_minimum_set(),
+                if possible { "possible" } else { "impossible" }
+            );
+            possible
+        })
+        .collect::<Vec<_>>();
+    trace!(
+        Level::Info,
+        "{} of {} games are possible with {}",
+        possible.len(),
+        games.len(),
+        real_amounts
+    );
+    possible
+        .iter()
+        .try_fold(0u32, |sum, game| sum.checked_add(game.id))
+        .ok_or_else(|| Error::Domain(String::from("The sum of the game ids overflows")))
+}
+
+pub fn sum_minimum_set_powers(games: &[Game]) -> common::Result<u32> {
+    games
+        .iter()
+        .try_fold(0u32, |sum, game| {
+            let set = game.get_minimum_set();
+            let power = set.power()?;
+            trace!(
+                Level::Debug,
+                "Game {} needs at least {}, a power of {}",
+                game.id,
+                set,
+                power
+            );
+            sum.checked_add(power)
+                .ok_or_else(|| Error::Domain(String::from("The sum of the powers overflows")))
+        })
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> common::Result<Self::Input> {
+        Ok(common::parse_lines(input)?)
+    }
+
+    fn part_one(games: &Self::Input) -> common::Result<Option<String>> {
+        let part_1_answer = sum_possible_game_ids(games, &REAL_AMOUNTS)?;
+        Ok(Some(part_1_answer.to_string()))
+    }
+
+    fn part_two(games: &Self::Input) -> common::Result<Option<String>> {
+        let part_2_answer = sum_minimum_set_powers(games)?;
+        Ok(Some(part_2_answer.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn possible_games() {
+        let games = Day02::parse(EXAMPLE).unwrap();
+        let possible = games
+            .iter()
+            .map(|game| game.is_possible(&REAL_AMOUNTS))
+            .collect::<Vec<_>>();
+        assert_eq!(possible, [true, true, false, false, true]);
+    }
+
+    #[test]
+    fn minimum_set_powers() {
+        let games = Day02::parse(EXAMPLE).unwrap();
+        let powers = games
+            .iter()
+            .map(|game| game.get_minimum_set().power().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(powers, [48, 12, 1560, 630, 36]);
+    }
+
+    #[test]
+    fn example_part_one() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part_one(&input).unwrap(), Some(String::from("8")));
+    }
+
+    #[test]
+    fn example_part_two() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part_two(&input).unwrap(), Some(String::from("2286")));
+    }
+
+    #[test]
+    fn overflowing_rounds_are_errors() {
+        let Err(error) = Day02::parse("Game 1: 4294967295 red, 1 red") else {
+            panic!("The round should overflow");
+        };
+        assert_eq!(
+            error.to_string(),
+            "Parse error at 1:25: Too many cubes in one round"
+        );
+    }
+
+    #[test]
+    fn overflowing_powers_are_errors() {
+        let input = Day02::parse("Game 1: 100000 red, 100000 green, 100000 blue").unwrap();
+        assert_eq!(
+            Day02::part_two(&input)
+                .unwrap_err()
+                .to_string(),
+            "The power of 100000 red, 100000 green, 100000 blue overflows"
+        );
+        let input = Day02::parse("Game 4294967295: 1 red\nGame 1: 1 red").unwrap();
+        assert!(Day02::part_one(&input).is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use common::{Generated, GeneratingSolution, Rng};
+
+use crate::Day03;
+
+const WIDTH: usize = 140;
+
+// Gears are the interesting symbol, so they come up more often
+const SYMBOLS: &[u8] = b"***#+$/@=%-&";
+
+struct PlacedNumber {
+    row: usize,
+    start: usize,
+    len: usize,
+    value: u64,
+}
+
+impl PlacedNumber {
+    fn touches(&self, (row, col): (usize, usize)) -> bool {
+        row + 1 >= self.row
+            && row <= self.row + 1
+            && col + 1 >= self.start
+            && col <= self.start + self.len
+    }
+}
+
+impl GeneratingSolution for Day03 {
+    /// Generates a schematic `size` rows high and 140 columns wide, like the
+    /// real one.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut input = String::new();
+        let mut numbers = vec![];
+        let mut symbols = vec![];
+        for row in 0..size {
+            let mut col = 0;
+            let mut after_digit = false;
+            while col < WIDTH {
+                let roll = rng.below(10);
+                if roll < 2 && !after_digit {
+                    let value = rng.between(1..=999) as u64;
+                    let digits = value.to_string();
+                    if col + digits.len() <= WIDTH {
+                        input += &digits;
+                        numbers.push(PlacedNumber {
+                            row,
+                            start: col,
+                            len: digits.len(),
+                            value,
+                        });
+                        col += digits.len();
+                        after_digit = true;
+                        continue;
+                    }
+                }
+                if roll == 2 {
+                    let symbol = *rng.pick(SYMBOLS) as char;
+                    input.push(symbol);
+                    symbols.push((symbol, (row, col)));
+                } else {
+                    input.push('.');
+                }
+                col += 1;
+                after_digit = false;
+            }
+            input.push('\n');
+        }
+
+        let part_number_sum = numbers
+            .iter()
+            .filter(|number| {
+                symbols
+                    .iter()
+                    .any(|&(_, pos)| number.touches(pos))
+            })
+            .map(|number| number.value)
+            .sum::<u64>();
+        let gear_ratio_sum = symbols
+            .iter()
+            .filter(|&&(symbol, _)| symbol == '*')
+            .map(|&(_, pos)| {
+                numbers
+                    .iter()
+                    .filter(|number| number.touches(pos))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|neighbors| neighbors.len() == 2)
+            .map(|neighbors| neighbors[0].value * neighbors[1].value)
+            .sum::<u64>();
+        Generated {
+            input,
+            answers: [
+                Some(part_number_sum.to_string()),
+                Some(gear_ratio_sum.to_string()),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    #[test]
+    fn generated_answers_match() {
+        for seed in 0..5 {
+            let generated = Day03::generate(&mut Rng::new(seed), 10);
+            let input = Day03::parse(&generated.input).unwrap();
+            assert_eq!(Day03::part_one(&input).unwrap(), generated.answers[0]);
+            assert_eq!(Day03::part_two(&input).unwrap(), generated.answers[1]);
+        }
+    }
+}
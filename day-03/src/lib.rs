@@ -0,0 +1,215 @@
+//! Day 3: Gear Ratios
+
+mod generate;
+
+use std::collections::HashSet;
+
+use common::{trace, Coordinates, Grid, Level, ParseError, Solution};
+
+/// Positions next to a symbol, where a digit makes its number a part number.
+pub fn get_eligible_number_locations(schematic: &Grid<char>) -> HashSet<Coordinates> {
+    schematic
+        .iter()
+        .filter(|(_, char)| !char.is_numeric() && *char != &'.')
+        .flat_map(|(pos, _)| schematic.neighbors_8(pos))
+        .collect::<_>()
+}
+
+pub struct SchematicNumber {
+    pub number: u32,
+    pub positions: Vec<Coordinates>,
+}
+
+impl SchematicNumber {
+    pub fn is_valid(&self, eligible_positions: &HashSet<Coordinates>) -> bool {
+        self.positions
+            .iter()
+            .any(|pos| eligible_positions.contains(pos))
+    }
+
+    pub fn is_neighboring(&self, pos: &Coordinates, schematic: &Grid<char>) -> bool {
+        self.positions
+            .iter()
+            .flat_map(|position| schematic.neighbors_8(*position))
+            .collect::<HashSet<_>>()
+            .contains(pos)
+    }
+}
+
+/// Every run of digits in the schematic, failing on a number too large to
+/// hold.
+pub fn get_schematic_numbers(schematic: &Grid<char>) -> Result<Vec<SchematicNumber>, ParseError> {
+    schematic
+        .row_runs(|char| char.is_ascii_digit())
+        .into_iter()
+        .map(|positions| {
+            let number = positions
+                .iter()
+                .filter_map(|pos| schematic.get(*pos)?.to_digit(10))
+                .try_fold(0u32, |number, digit| {
+                    number
+                        .checked_mul(10)?
+                        .checked_add(digit)
+                })
+                .ok_or_else(|| {
+                    let (row, col) = positions[0];
+                    let mut error = ParseError::new("Number too large");
+                    error.line = Some(row + 1);
+                    error.column = Some(col + 1);
+                    error
+                })?;
+            Ok(SchematicNumber { number, positions })
+        })
+        .collect()
+}
+
+/// Sums the products of the two numbers next to each `*` which touches
+/// exactly two numbers.
+pub fn sum_gear_ratios(schematic: &Grid<char>, schematic_numbers: &[SchematicNumber]) -> u128 {
+    schematic
+        .iter()
+        .filter(|(_, char)| *char == &'*')
+        .map(|(pos, _)| {
+            let neighbors = schematic_numbers
+                .iter()
+                .filter(|num| num.is_neighboring(&pos, schematic))
+                .map(|n| n.number)
+                .collect::<Vec<_>>();
+            trace!(
+                Level::Debug,
+                "* at line {}, column {} touches {:?}{}",
+                pos.0 + 1,
+                pos.1 + 1,
+                neighbors,
+                if neighbors.len() == 2 { ", a gear" } else { "" }
+            );
+            neighbors
+        })
+        .filter_map(|neighbors| {
+            if neighbors.len() == 2 {
+                Some(neighbors[0] as u128 * neighbors[1] as u128)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+pub fn sum_part_numbers(schematic: &Grid<char>, schematic_numbers: &[SchematicNumber]) -> u64 {
+    let eligible_positions = get_eligible_number_locations(schematic);
+    schematic_numbers
+        .iter()
+        .filter(|num| {
+            let valid = num.is_valid(&eligible_positions);
+            trace!(
+                Level::Debug,
+                "{} at line {}, column {} is {}",
+                num.number,
+                num.positions[0].0 + 1,
+                num.positions[0].1 + 1,
+                if valid {
+                    "a part number"
+                } else {
+                    "not next to a symbol"
+                }
+            );
+            valid
+        })
+        .map(|num| num.number as u64)
+        .sum()
+}
+
+/// The schematic with the numbers read from it.
+pub struct Schematic {
+    pub grid: Grid<char>,
+    pub numbers: Vec<SchematicNumber>,
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+
+    fn parse(input: &str) -> common::Result<Self::Input> {
+        let grid = input.parse()?;
+        let numbers = get_schematic_numbers(&grid)?;
+        Ok(Schematic { grid, numbers })
+    }
+
+    fn part_one(schematic: &Self::Input) -> common::Result<Option<String>> {
+        let part_1_answer = sum_part_numbers(&schematic.grid, &schematic.numbers);
+        Ok(Some(part_1_answer.to_string()))
+    }
+
+    fn part_two(schematic: &Self::Input) -> common::Result<Option<String>> {
+        let part_2_answer = sum_gear_ratios(&schematic.grid, &schematic.numbers);
+        Ok(Some(part_2_answer.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn part_numbers() {
+        let schematic = Day03::parse(EXAMPLE).unwrap();
+        let eligible_positions = get_eligible_number_locations(&schematic.grid);
+        let (valid, invalid): (Vec<_>, Vec<_>) = schematic
+            .numbers
+            .into_iter()
+            .partition(|num| num.is_valid(&eligible_positions));
+        assert_eq!(valid.len(), 8);
+        let invalid = invalid
+            .iter()
+            .map(|num| num.number)
+            .collect::<Vec<_>>();
+        assert_eq!(invalid, [114, 58]);
+    }
+
+    #[test]
+    fn gear_ratios() {
+        let schematic = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(sum_gear_ratios(&schematic.grid, &schematic.numbers), 467835);
+    }
+
+    #[test]
+    fn example_part_one() {
+        let input = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part_one(&input).unwrap(), Some(String::from("4361")));
+    }
+
+    #[test]
+    fn example_part_two() {
+        let input = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            Day03::part_two(&input).unwrap(),
+            Some(String::from("467835"))
+        );
+    }
+
+    #[test]
+    fn oversized_numbers_are_errors() {
+        let Err(error) = Day03::parse("............\n12345678901*") else {
+            panic!("The number should not fit");
+        };
+        assert_eq!(error.to_string(), "Parse error at 2:1: Number too large");
+    }
+
+    #[test]
+    fn large_gears_do_not_overflow() {
+        let input = Day03::parse("4294967295*4294967295").unwrap();
+        assert_eq!(
+            Day03::part_one(&input).unwrap(),
+            Some(String::from("8589934590"))
+        );
+        assert_eq!(
+            Day03::part_two(&input).unwrap(),
+            Some(String::from("18446744065119617025"))
+        );
+    }
+}
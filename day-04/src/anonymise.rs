@@ -0,0 +1,73 @@
+use common::{AnonymisingSolution, Error, Rng, Span};
+
+use crate::{generate::format_numbers, Day04, ScratchCard};
+
+/// Scratch card numbers run up to this.
+const LARGEST: u64 = 99;
+
+impl AnonymisingSolution for Day04 {
+    /// Swaps the numbers of each card for others through a permutation of its
+    /// own, and shuffles each list. Every card keeps its number of matches,
+    /// so both answers are kept.
+    fn anonymise(input: &str, rng: &mut Rng) -> common::Result<String> {
+        let mut output = String::new();
+        for line in Span::new(input).lines() {
+            // Checks the line, so that it can be taken apart below
+            line.parse::<ScratchCard>()?;
+            let (header, tail) = line.split_once(": ")?;
+            let (winning_numbers, numbers) = tail.split_once(" | ")?;
+
+            let mut permutation = (0..=LARGEST).collect::<Vec<_>>();
+            rng.shuffle(&mut permutation[1..]);
+            let mut permuted = |numbers: Span<'_>| {
+                let mut numbers = numbers
+                    .numbers::<u64>()?
+                    .iter()
+                    .map(|&number| {
+                        permutation
+                            .get(number as usize)
+                            .copied()
+                            .ok_or_else(|| Error::Domain(format!("{} is past {}", number, LARGEST)))
+                    })
+                    .collect::<common::Result<Vec<_>>>()?;
+                rng.shuffle(&mut numbers);
+                Ok::<_, Error>(format_numbers(&numbers))
+            };
+            output += &format!(
+                "{}: {} | {}\n",
+                header.as_str(),
+                permuted(winning_numbers)?,
+                permuted(numbers)?
+            );
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn answers_are_kept() {
+        let cards = Day04::parse(EXAMPLE).unwrap();
+        for seed in 0..10 {
+            let anonymised = Day04::anonymise(EXAMPLE, &mut Rng::new(seed)).unwrap();
+            let permuted = Day04::parse(&anonymised).unwrap();
+            assert_ne!(anonymised, EXAMPLE);
+            assert!(anonymised.starts_with("Card 1: "));
+            assert_eq!(
+                Day04::part_one(&permuted).unwrap(),
+                Day04::part_one(&cards).unwrap()
+            );
+            assert_eq!(
+                Day04::part_two(&permuted).unwrap(),
+                Day04::part_two(&cards).unwrap()
+            );
+        }
+    }
+}
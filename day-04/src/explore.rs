@@ -0,0 +1,106 @@
+use common::{not_found, ExploringSolution, ReplCommand, Result, Span};
+
+use crate::{Day04, ScratchCard, ScratchCardCounter};
+
+/// The card numbered by `number`, counting from 1 like the table does.
+fn find_card<'a>(cards: &'a [ScratchCard], number: Span<'_>) -> Result<(usize, &'a ScratchCard)> {
+    let number = number.value::<usize>()?;
+    number
+        .checked_sub(1)
+        .and_then(|index| cards.get(index))
+        .map(|card| (number, card))
+        .ok_or_else(|| not_found(format_args!("card {}", number)))
+}
+
+fn card(cards: &[ScratchCard], args: Span<'_>) -> Result<String> {
+    let [number] = args.split_words()?;
+    let (number, card) = find_card(cards, number)?;
+    let matches = card
+        .numbers
+        .iter()
+        .filter(|number| card.winning_numbers.contains(number))
+        .map(u8::to_string)
+        .collect::<Vec<_>>();
+    Ok(format!(
+        "Card {} matches {} winning numbers ({}), worth {} points",
+        number,
+        matches.len(),
+        matches.join(", "),
+        card.get_points()?
+    ))
+}
+
+fn copies(cards: &[ScratchCard], args: Span<'_>) -> Result<String> {
+    let [number] = args.split_words()?;
+    let (number, _) = find_card(cards, number)?;
+    // Only the cards before it win copies of it
+    let mut counter = ScratchCardCounter::default();
+    let mut held = 0;
+    for card in &cards[..number] {
+        held = counter.add(card)?;
+    }
+    Ok(format!(
+        "Holding {} of card {} once the cards before it pay out",
+        held, number
+    ))
+}
+
+impl ExploringSolution for Day04 {
+    const COMMANDS: &'static [ReplCommand] = &[
+        ReplCommand {
+            name: "card",
+            args: "<number>",
+            help: "Show which winning numbers a card matches and what it is worth",
+        },
+        ReplCommand {
+            name: "copies",
+            args: "<number>",
+            help: "Count the copies of a card held, the original included",
+        },
+    ];
+
+    fn explore(cards: &Self::Input, command: &str, args: Span<'_>) -> Option<Result<String>> {
+        match command {
+            "card" => Some(card(cards, args)),
+            "copies" => Some(copies(cards, args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn run(line: &str) -> Result<String> {
+        let cards = Day04::parse(EXAMPLE).unwrap();
+        let (command, args) = Span::new(line)
+            .split_first_word()
+            .unwrap();
+        Day04::explore(&cards, command.as_str(), args).unwrap()
+    }
+
+    #[test]
+    fn card_matches() {
+        assert_eq!(
+            run("card 1").unwrap(),
+            "Card 1 matches 4 winning numbers (83, 86, 17, 48), worth 8 points"
+        );
+    }
+
+    #[test]
+    fn copies_held() {
+        assert_eq!(
+            run("copies 5").unwrap(),
+            "Holding 14 of card 5 once the cards before it pay out"
+        );
+        assert_eq!(
+            run("copies 0").unwrap_err().to_string(),
+            "No card 0 in the input"
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use common::{Generated, GeneratingSolution, Rng};
+
+use crate::Day04;
+
+const WINNING_NUMBERS: usize = 10;
+const NUMBERS: usize = 25;
+
+pub(crate) fn format_numbers(numbers: &[u64]) -> String {
+    numbers
+        .iter()
+        .map(|number| format!("{:>2}", number))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl GeneratingSolution for Day04 {
+    /// Generates `size` cards shaped like the real ones, none of which wins
+    /// copies past the end of the table.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut input = String::new();
+        let mut points = 0u64;
+        let mut copies = vec![1u64; size];
+        for i in 0..size {
+            // Copies grow exponentially once a card wins more than one copy on
+            // average, so most cards win nothing
+            let matches = match rng.below(20) {
+                0..=12 => 0,
+                13..=18 => rng.between(1..=2),
+                _ => rng.between(3..=WINNING_NUMBERS as i64),
+            };
+            let matches = (matches as usize).min(size - i - 1);
+
+            let mut pool = (1..=99).collect::<Vec<u64>>();
+            rng.shuffle(&mut pool);
+            let winning_numbers = &pool[..WINNING_NUMBERS];
+            let mut numbers = pool[WINNING_NUMBERS..WINNING_NUMBERS + NUMBERS - matches].to_vec();
+            numbers.extend_from_slice(&winning_numbers[..matches]);
+            rng.shuffle(&mut numbers);
+
+            if matches > 0 {
+                points += 1 << (matches - 1);
+            }
+            for j in 1..=matches {
+                copies[i + j] += copies[i];
+            }
+            input += &format!(
+                "Card {:>3}: {} | {}\n",
+                i + 1,
+                format_numbers(winning_numbers),
+                format_numbers(&numbers)
+            );
+        }
+        Generated {
+            input,
+            answers: [
+                Some(points.to_string()),
+                Some(copies.iter().sum::<u64>().to_string()),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    #[test]
+    fn generated_answers_match() {
+        for seed in 0..20 {
+            let generated = Day04::generate(&mut Rng::new(seed), 30);
+            let input = Day04::parse(&generated.input).unwrap();
+            assert_eq!(Day04::part_one(&input).unwrap(), generated.answers[0]);
+            assert_eq!(Day04::part_two(&input).unwrap(), generated.answers[1]);
+        }
+    }
+}
@@ -0,0 +1,283 @@
+//! Day 4: Scratchcards
+
+mod anonymise;
+mod explore;
+mod generate;
+
+use common::{trace, Error, Level, Line, ParseError, Solution, Span, StreamingSolution};
+use std::{
+    collections::{HashSet, VecDeque},
+    str::FromStr,
+};
+
+pub struct ScratchCard {
+    pub winning_numbers: HashSet<u8>,
+    pub numbers: Vec<u8>,
+}
+
+impl ScratchCard {
+    pub fn get_winning_number_count(&self) -> usize {
+        self.numbers
+            .iter()
+            .filter(|number| self.winning_numbers.contains(number))
+            .count()
+    }
+
+    /// The points of the card, which double with every match after the
+    /// first, or an error once they pass a u64.
+    pub fn get_points(&self) -> common::Result<u64> {
+        let num_matches = self.get_winning_number_count();
+        if num_matches == 0 {
+            Ok(0)
+        } else {
+            u32::try_from(num_matches - 1)
+                .ok()
+                .and_then(|shift| 1u64.checked_shl(shift))
+                .ok_or_else(|| {
+                    Error::Domain(format!(
+                        "A card with {} matches is worth too many points",
+                        num_matches
+                    ))
+                })
+        }
+    }
+}
+
+impl FromStr for ScratchCard {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, tail) = Span::new(s).split_once(": ")?;
+        let (winning_numbers_str, numbers_str) = tail.split_once(" | ")?;
+        Ok(ScratchCard {
+            winning_numbers: winning_numbers_str
+                .numbers()?
+                .into_iter()
+                .collect(),
+            numbers: numbers_str.numbers()?,
+        })
+    }
+}
+
+/// Tallies the cards held as each win is paid out in copies of the cards
+/// that follow, reading the cards in order.
+#[derive(Default)]
+pub struct ScratchCardCounter {
+    /// Copies won so far of the cards after the last one added. They double
+    /// with every card that wins, so only a u128 holds them for long.
+    pending_copies: VecDeque<u128>,
+    card_count: u128,
+    /// How many cards of the table have been added.
+    cards_added: usize,
+}
+
+impl ScratchCardCounter {
+    /// Adds the next card of the table, giving how many of it are held.
+    pub fn add(&mut self, card: &ScratchCard) -> common::Result<u128> {
+        let amount_of_this_card = self
+            .pending_copies
+            .pop_front()
+            .unwrap_or(0)
+            .checked_add(1)
+            .ok_or_else(too_many_copies)?;
+        self.card_count = self
+            .card_count
+            .checked_add(amount_of_this_card)
+            .ok_or_else(too_many_copies)?;
+        self.cards_added += 1;
+        let win_count = card.get_winning_number_count();
+        trace!(
+            Level::Debug,
+            "Card {}: holding {}, each winning a copy of the next {} cards",
+            self.cards_added,
+            amount_of_this_card,
+            win_count
+        );
+        if self.pending_copies.len() < win_count {
+            self.pending_copies.resize(win_count, 0);
+        }
+        for copies in self
+            .pending_copies
+            .iter_mut()
+            .take(win_count)
+        {
+            *copies = copies
+                .checked_add(amount_of_this_card)
+                .ok_or_else(too_many_copies)?;
+        }
+        Ok(amount_of_this_card)
+    }
+
+    /// The number of cards held once every card has been added.
+    pub fn card_count(&self) -> common::Result<u128> {
+        if self.pending_copies.is_empty() {
+            Ok(self.card_count)
+        } else {
+            Err(Error::Domain(String::from(
+                "Cards win copies of cards past the end of the table",
+            )))
+        }
+    }
+}
+
+fn too_many_copies() -> Error {
+    Error::Domain(String::from("Too many copies of cards to count"))
+}
+
+/// Counts the cards held once every win has been paid out in copies of the
+/// cards that follow.
+pub fn count_scratch_cards(scratch_cards: &[ScratchCard]) -> common::Result<u128> {
+    let mut counter = ScratchCardCounter::default();
+    for card in scratch_cards {
+        counter.add(card)?;
+    }
+    counter.card_count()
+}
+
+/// Adds a card's points to a running total.
+fn add_points(sum: u64, card: &ScratchCard) -> common::Result<u64> {
+    sum.checked_add(card.get_points()?)
+        .ok_or_else(|| Error::Domain(String::from("Points overflow")))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<ScratchCard>;
+
+    fn parse(input: &str) -> common::Result<Self::Input> {
+        Ok(common::parse_lines(input)?)
+    }
+
+    fn part_one(scratch_cards: &Self::Input) -> common::Result<Option<String>> {
+        let part_1_answer = scratch_cards
+            .iter()
+            .enumerate()
+            .try_fold(0, |sum, (i, c)| {
+                let sum = add_points(sum, c)?;
+                trace!(
+                    Level::Debug,
+                    "Card {}: {} winning numbers, {} points",
+                    i + 1,
+                    c.get_winning_number_count(),
+                    c.get_points()?
+                );
+                common::Result::Ok(sum)
+            })?;
+        Ok(Some(part_1_answer.to_string()))
+    }
+
+    fn part_two(scratch_cards: &Self::Input) -> common::Result<Option<String>> {
+        let part_2_answer = count_scratch_cards(scratch_cards)?;
+        Ok(Some(part_2_answer.to_string()))
+    }
+}
+
+impl StreamingSolution for Day04 {
+    fn solve_lines(
+        lines: &mut dyn Iterator<Item = common::Result<Line>>,
+    ) -> common::Result<[Option<String>; 2]> {
+        let mut points = 0;
+        let mut counter = ScratchCardCounter::default();
+        for line in lines {
+            let card = line?.parse::<ScratchCard>()?;
+            points = add_points(points, &card)?;
+            counter.add(&card)?;
+        }
+        let card_count = counter.card_count()?;
+        Ok([Some(points.to_string()), Some(card_count.to_string())])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Lines;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn card_points() {
+        let cards = Day04::parse(EXAMPLE).unwrap();
+        let points = cards
+            .iter()
+            .map(|card| card.get_points().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(points, [8, 2, 2, 1, 0, 0]);
+    }
+
+    #[test]
+    fn example_part_one() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part_one(&input).unwrap(), Some(String::from("13")));
+    }
+
+    #[test]
+    fn example_part_two() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part_two(&input).unwrap(), Some(String::from("30")));
+    }
+
+    #[test]
+    fn streamed_example() {
+        let answers = Day04::solve_lines(&mut Lines::new(EXAMPLE.as_bytes(), "example")).unwrap();
+        assert_eq!(
+            answers,
+            [Some(String::from("13")), Some(String::from("30"))]
+        );
+    }
+
+    #[test]
+    fn points_pass_u32() {
+        // Duplicate numbers each match, so 40 ones win 40 times
+        let input = format!("Card 1: 1 |{}\n", " 1".repeat(40)) + &"Card 2: 1 | 2\n".repeat(40);
+        let cards = Day04::parse(&input).unwrap();
+        assert_eq!(
+            Day04::part_one(&cards).unwrap(),
+            Some((1u64 << 39).to_string())
+        );
+        let [points, _] = Day04::solve_lines(&mut Lines::new(input.as_bytes(), "test")).unwrap();
+        assert_eq!(points, Some((1u64 << 39).to_string()));
+    }
+
+    #[test]
+    fn overflowing_points_are_errors() {
+        let input = format!("Card 1: 1 |{}\n", " 1".repeat(65));
+        let cards = Day04::parse(&input).unwrap();
+        assert!(Day04::part_one(&cards).is_err());
+        assert!(Day04::solve_lines(&mut Lines::new(input.as_bytes(), "test")).is_err());
+    }
+
+    #[test]
+    fn copies_past_the_end_of_the_table() {
+        let cards = Day04::parse("Card 1: 1 | 1\n").unwrap();
+        assert!(count_scratch_cards(&cards).is_err());
+    }
+
+    #[test]
+    fn streamed_counts_pass_u32() {
+        // Every card wins a copy of the next two, so the copies grow like the
+        // Fibonacci numbers, passing u32::MAX by the 46th card
+        let mut input = (1..=60)
+            .map(|i| format!("Card {}: 1 2 | 1 2\n", i))
+            .collect::<String>();
+        input += "Card 61: 1 | 2\nCard 62: 1 | 2\n";
+        let [points, cards] =
+            Day04::solve_lines(&mut Lines::new(input.as_bytes(), "test")).unwrap();
+        assert_eq!(points, Some(String::from("120")));
+        let cards = cards.unwrap().parse::<u128>().unwrap();
+        assert!(cards > u128::from(u32::MAX));
+    }
+
+    #[test]
+    fn overflowing_counts_are_errors() {
+        let input = (1..=200)
+            .map(|i| format!("Card {}: 1 2 | 1 2\n", i))
+            .collect::<String>();
+        let error = Day04::solve_lines(&mut Lines::new(input.as_bytes(), "test")).unwrap_err();
+        assert_eq!(error.to_string(), "Too many copies of cards to count");
+    }
+}
@@ -0,0 +1,94 @@
+use common::{AnonymisingSolution, Error, Rng, Span};
+
+use crate::{map_header, Day05, MappingRange, CATEGORIES};
+
+/// Real almanacs map the whole of `0..LIMIT`, so shifting by up to this
+/// takes the numbers well away from theirs.
+const LIMIT: u64 = 1 << 32;
+
+impl AnonymisingSolution for Day05 {
+    /// Shifts every seed and the start of every range by the same amount, and
+    /// lists the ranges of each map in a new order. Every location shifts by
+    /// that amount too, so the lowest one stays the lowest.
+    fn anonymise(input: &str, rng: &mut Rng) -> common::Result<String> {
+        let input = Span::new(input);
+        let seeds = input
+            .lines()
+            .next()
+            .ok_or_else(|| input.error("Missing seed numbers"))?
+            .labelled_numbers::<u64>("seeds")?;
+        let maps = CATEGORIES
+            .windows(2)
+            .map(|pair| {
+                let header = map_header(pair[0], pair[1]);
+                let ranges = input
+                    .section(&header)?
+                    .lines()
+                    .map(|line| line.parse::<MappingRange>())
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok((header, ranges))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let shift = rng.between(1..=LIMIT as i64) as u64;
+        let shifted = |number: u64| {
+            number
+                .checked_add(shift)
+                .ok_or_else(|| Error::Domain(format!("Cannot shift {} by {}", number, shift)))
+        };
+
+        let seeds = seeds
+            .iter()
+            .map(|&seed| shifted(seed).map(|seed| seed.to_string()))
+            .collect::<common::Result<Vec<_>>>()?;
+        let mut output = format!("seeds: {}\n", seeds.join(" "));
+        for (header, ranges) in maps {
+            let mut lines = ranges
+                .iter()
+                .map(|range| {
+                    Ok(format!(
+                        "{} {} {}\n",
+                        shifted(range.destination_start)?,
+                        shifted(range.source_start)?,
+                        range.length
+                    ))
+                })
+                .collect::<common::Result<Vec<_>>>()?;
+            rng.shuffle(&mut lines);
+            output += &format!("\n{}\n{}", header, lines.concat());
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+    use crate::feed_forward;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn locations_shift_with_the_seeds() {
+        let almanac = Day05::parse(EXAMPLE).unwrap();
+        for seed in 0..10 {
+            let anonymised = Day05::anonymise(EXAMPLE, &mut Rng::new(seed)).unwrap();
+            let shifted = Day05::parse(&anonymised).unwrap();
+            assert_ne!(anonymised, EXAMPLE);
+            let shift = shifted.seed_numbers[0] - almanac.seed_numbers[0];
+            for (&original, &seed) in almanac
+                .seed_numbers
+                .iter()
+                .zip(&shifted.seed_numbers)
+            {
+                assert_eq!(seed, original + shift);
+                assert_eq!(
+                    feed_forward(&shifted.maps, seed),
+                    feed_forward(&almanac.maps, original) + shift
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use common::{not_found, ExploringSolution, ReplCommand, Result, Span};
+
+use crate::{feed_forward, Almanac, Day05, CATEGORIES};
+
+fn locate(almanac: &Almanac, args: Span<'_>) -> Result<String> {
+    let [seed] = args.split_words()?;
+    let mut number = seed.value::<u64>()?;
+    let mut path = vec![format!("Seed {}", number)];
+    for (tree, category) in almanac
+        .maps
+        .iter()
+        .zip(&CATEGORIES[1..])
+    {
+        number = tree.map(number).unwrap_or(number);
+        path.push(format!("{} {}", category, number));
+    }
+    Ok(path.join(", "))
+}
+
+fn map(almanac: &Almanac, args: Span<'_>) -> Result<String> {
+    let [category, number] = args.split_words()?;
+    // The last category has no map of its own
+    let index = CATEGORIES[..CATEGORIES.len() - 1]
+        .iter()
+        .position(|&name| name == category.as_str())
+        .ok_or_else(|| {
+            category.error(format!(
+                "Expected one of {}",
+                CATEGORIES[..CATEGORIES.len() - 1].join(", ")
+            ))
+        })?;
+    let number = number.value::<u64>()?;
+    let mapped = almanac.maps[index]
+        .map(number)
+        .unwrap_or(number);
+    Ok(format!(
+        "{} {} corresponds to {} {}",
+        CATEGORIES[index],
+        number,
+        CATEGORIES[index + 1],
+        mapped
+    ))
+}
+
+fn lowest(almanac: &Almanac, args: Span<'_>) -> Result<String> {
+    let [] = args.split_words()?;
+    let (location, seed) = almanac
+        .seed_numbers
+        .iter()
+        .map(|&seed| (feed_forward(&almanac.maps, seed), seed))
+        .min()
+        .ok_or_else(|| not_found("seeds"))?;
+    Ok(format!(
+        "Seed {} is planted lowest, at location {}",
+        seed, location
+    ))
+}
+
+impl ExploringSolution for Day05 {
+    const COMMANDS: &'static [ReplCommand] = &[
+        ReplCommand {
+            name: "locate",
+            args: "<seed>",
+            help: "Follow a seed through every map to its location",
+        },
+        ReplCommand {
+            name: "map",
+            args: "<category> <number>",
+            help: "Map a number of a category, such as soil, to the next category",
+        },
+        ReplCommand {
+            name: "lowest",
+            args: "",
+            help: "Find the seed planted at the lowest location",
+        },
+    ];
+
+    fn explore(almanac: &Self::Input, command: &str, args: Span<'_>) -> Option<Result<String>> {
+        match command {
+            "locate" => Some(locate(almanac, args)),
+            "map" => Some(map(almanac, args)),
+            "lowest" => Some(lowest(almanac, args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{Error, Solution};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn run(line: &str) -> Result<String> {
+        let almanac = Day05::parse(EXAMPLE).unwrap();
+        let (command, args) = Span::new(line)
+            .split_first_word()
+            .unwrap();
+        Day05::explore(&almanac, command.as_str(), args).unwrap()
+    }
+
+    #[test]
+    fn seed_locations() {
+        assert_eq!(
+            run("locate 79").unwrap(),
+            "Seed 79, soil 81, fertilizer 81, water 81, light 74, temperature 78, humidity 78, location 82"
+        );
+        assert_eq!(
+            run("lowest").unwrap(),
+            "Seed 13 is planted lowest, at location 35"
+        );
+    }
+
+    #[test]
+    fn single_maps() {
+        assert_eq!(
+            run("map light 77").unwrap(),
+            "light 77 corresponds to temperature 45"
+        );
+        match run("map location 1") {
+            Err(Error::Parse(error)) => assert_eq!(error.column, Some(5)),
+            _ => panic!("Expected a parse error"),
+        }
+    }
+}
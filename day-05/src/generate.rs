@@ -0,0 +1,91 @@
+use common::{Generated, GeneratingSolution, Rng};
+
+use crate::{map_header, Day05, MappingRange, CATEGORIES};
+
+const SEEDS: usize = 20;
+
+const LIMIT: u64 = 1 << 32;
+
+/// Non-overlapping ranges covering about half of `0..LIMIT`.
+fn generate_map(rng: &mut Rng, size: usize) -> Vec<MappingRange> {
+    let mut bounds = (0..2 * size)
+        .map(|_| rng.below(LIMIT))
+        .collect::<Vec<_>>();
+    bounds.sort();
+    bounds.dedup();
+    bounds
+        .chunks_exact(2)
+        .map(|bounds| {
+            let length = bounds[1] - bounds[0];
+            MappingRange {
+                source_start: bounds[0],
+                destination_start: rng.below(LIMIT - length),
+                length,
+            }
+        })
+        .collect()
+}
+
+/// Maps `x` by looking through every range, rather than through a tree.
+fn map(ranges: &[MappingRange], x: u64) -> u64 {
+    ranges
+        .iter()
+        .find(|range| range.source_start <= x && x < range.source_start + range.length)
+        .map_or(x, |range| range.destination_start + x - range.source_start)
+}
+
+impl GeneratingSolution for Day05 {
+    /// Generates an almanac of 20 seeds whose maps each hold `size` ranges.
+    /// Part 2 is not solved yet, so only part 1 has an answer.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let seeds = (0..SEEDS)
+            .map(|_| rng.below(LIMIT))
+            .collect::<Vec<_>>();
+        let maps = (1..CATEGORIES.len())
+            .map(|_| generate_map(rng, size))
+            .collect::<Vec<_>>();
+
+        let lowest_location = seeds
+            .iter()
+            .map(|&seed| {
+                maps.iter()
+                    .fold(seed, |x, ranges| map(ranges, x))
+            })
+            .min();
+
+        let seeds = seeds
+            .iter()
+            .map(u64::to_string)
+            .collect::<Vec<_>>();
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+        for (pair, ranges) in CATEGORIES.windows(2).zip(&maps) {
+            input += &format!("\n{}\n", map_header(pair[0], pair[1]));
+            for range in ranges {
+                input += &format!(
+                    "{} {} {}\n",
+                    range.destination_start, range.source_start, range.length
+                );
+            }
+        }
+        Generated {
+            input,
+            answers: [lowest_location.map(|location| location.to_string()), None],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    #[test]
+    fn generated_answers_match() {
+        for seed in 0..20 {
+            let generated = Day05::generate(&mut Rng::new(seed), 30);
+            let input = Day05::parse(&generated.input).unwrap();
+            assert_eq!(Day05::part_one(&input).unwrap(), generated.answers[0]);
+        }
+    }
+}
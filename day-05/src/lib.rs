@@ -0,0 +1,424 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+mod anonymise;
+mod explore;
+mod generate;
+
+use std::str::FromStr;
+
+use common::{trace, tracing, Error, Level, ParseError, Solution, Span};
+
+/// One line of an almanac map, sending `length` numbers from `source_start`
+/// on to `destination_start`.
+#[derive(Clone, Copy, Debug)]
+pub struct MappingRange {
+    pub source_start: u64,
+    pub destination_start: u64,
+    pub length: u64,
+}
+
+/// Where a range lies relative to a point.
+#[derive(PartialEq, Eq, Debug)]
+pub enum RangeQueryResult {
+    LeftOf,
+    RightOf,
+    Contains,
+}
+
+impl MappingRange {
+    pub fn query(&self, point: u64) -> RangeQueryResult {
+        if point < self.source_start {
+            RangeQueryResult::RightOf
+        } else if self.source_start + self.length <= point {
+            RangeQueryResult::LeftOf
+        } else {
+            RangeQueryResult::Contains
+        }
+    }
+
+    /// Maps a point inside the range.
+    ///
+    /// # Panics
+    ///
+    /// If `point` is outside the range.
+    pub fn map(&self, point: u64) -> u64 {
+        match self.query(point) {
+            RangeQueryResult::Contains => self.destination_start + point - self.source_start,
+            _ => panic!("Query {} falls outside of range {:?}", point, self),
+        }
+    }
+
+    // Part 2 is not solved yet, so nothing maps whole ranges so far.
+    #[allow(dead_code)]
+    fn fully_contains(&self, start: &u64, end: &u64) -> bool {
+        self.source_start <= *start && (self.source_start + self.length) >= *end
+    }
+
+    #[allow(dead_code)]
+    fn map_range(&self, other: &MappingRange) -> Vec<MappingRange> {
+        // Vector of all points which are on (or adjacent to) a boundary of either range.
+        // The boundaries of the split ranges are guaranteed to be in this vector.
+        let mut critical_points = vec![
+            self.source_start,
+            // Last point in self
+            self.source_start + self.length - 1,
+            // First point after end of self
+            self.source_start + self.length,
+            // First point in other
+            other.source_start,
+            // Last point in other
+            other.source_start + other.length - 1,
+            // First point after end of other
+            other.source_start + other.length,
+        ];
+        if self.source_start > 0 {
+            // Last point before start of self
+            critical_points.push(self.source_start - 1);
+        }
+        if other.source_start > 0 {
+            // Last point before start of other
+            critical_points.push(other.source_start - 1);
+        }
+        critical_points.sort();
+        critical_points.dedup();
+        critical_points
+            .windows(2)
+            .map(|slice| (slice[0], slice[1]))
+            .filter(|(start, end)| other.fully_contains(start, end))
+            .map(|(start, end)| {
+                if self.fully_contains(&start, &end) {
+                    MappingRange {
+                        source_start: start,
+                        destination_start: self.map(start),
+                        length: end - start,
+                    }
+                } else {
+                    MappingRange {
+                        source_start: start,
+                        destination_start: start,
+                        length: end - start,
+                    }
+                }
+            })
+            .collect::<_>()
+    }
+}
+
+impl FromStr for MappingRange {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let span = Span::new(s);
+        match span.numbers::<u64>()?[..] {
+            // An empty range would never be split off when building a tree
+            [_, _, 0] => Err(span.error("Expected a range of at least one number")),
+            [destination_start, source_start, length]
+                if source_start
+                    .checked_add(length)
+                    .is_none()
+                    || destination_start
+                        .checked_add(length)
+                        .is_none() =>
+            {
+                Err(span.error("Range ends past the largest number"))
+            }
+            [destination_start, source_start, length] => Ok(MappingRange {
+                source_start,
+                destination_start,
+                length,
+            }),
+            _ => Err(span.error("Expected exactly 3 numbers")),
+        }
+    }
+}
+
+/// An interval tree over the ranges of one almanac map.
+#[derive(Debug)]
+pub struct RangeTreeNode {
+    center: u64,
+    left: Option<Box<RangeTreeNode>>,
+    right: Option<Box<RangeTreeNode>>,
+    overlapping_sorted_start: Vec<MappingRange>,
+    overlapping_sorted_end: Vec<MappingRange>,
+}
+
+impl RangeTreeNode {
+    pub fn create_tree(ranges: Vec<MappingRange>) -> Option<RangeTreeNode> {
+        // https://en.wikipedia.org/wiki/Interval_tree#Construction
+        if ranges.is_empty() {
+            return None;
+        }
+        let min = ranges
+            .iter()
+            .map(|range| range.source_start)
+            .min()?;
+        let max = ranges
+            .iter()
+            .map(|range| range.source_start)
+            .max()?;
+        let center = min + ((max - min) >> 1);
+        let mut left = vec![];
+        let mut right = vec![];
+        let mut overlapping = vec![];
+        for range in ranges {
+            match range.query(center) {
+                RangeQueryResult::LeftOf => left.push(range),
+                RangeQueryResult::RightOf => right.push(range),
+                RangeQueryResult::Contains => overlapping.push(range),
+            }
+        }
+        let left_tree = RangeTreeNode::create_tree(left);
+        let right_tree = RangeTreeNode::create_tree(right);
+        let mut overlapping_sorted_start = overlapping.clone();
+        overlapping_sorted_start.sort_by_key(|r| r.source_start);
+        let mut overlapping_sorted_end = overlapping.clone();
+        overlapping_sorted_end.sort_by_key(|r| std::cmp::Reverse(r.source_start + r.length));
+        Some(RangeTreeNode {
+            center,
+            left: left_tree.map(Box::new),
+            right: right_tree.map(Box::new),
+            overlapping_sorted_start,
+            overlapping_sorted_end,
+        })
+    }
+
+    /// Maps `x` through the range containing it, if any.
+    pub fn map(&self, x: u64) -> Option<u64> {
+        if x < self.center {
+            self.overlapping_sorted_start
+                .iter()
+                .take_while(|range| range.query(x) == RangeQueryResult::Contains)
+                .map(|range| range.map(x))
+                .next()
+                .or_else(|| {
+                    self.left
+                        .as_ref()
+                        .and_then(|node| node.map(x))
+                })
+        } else if x > self.center {
+            self.overlapping_sorted_end
+                .iter()
+                .take_while(|range| range.query(x) == RangeQueryResult::Contains)
+                .map(|range| range.map(x))
+                .next()
+                .or_else(|| {
+                    self.right
+                        .as_ref()
+                        .and_then(|node| node.map(x))
+                })
+        } else {
+            self.overlapping_sorted_start
+                .iter()
+                .take_while(|range| range.query(x) == RangeQueryResult::Contains)
+                .map(|range| range.map(x))
+                .next()
+        }
+    }
+
+    #[allow(dead_code)]
+    fn get_overlapping_ranges(&self, _range: &MappingRange) -> common::Result<Vec<MappingRange>> {
+        // https://en.wikipedia.org/wiki/Interval_tree#With_an_interval
+        Err(Error::Domain(String::from(
+            "Querying the ranges overlapping a range is not implemented yet",
+        )))
+    }
+}
+
+fn get_range_tree(input: Span<'_>, header: &str) -> Result<RangeTreeNode, ParseError> {
+    let section = input.section(header)?;
+    let ranges = section
+        .lines()
+        .map(|line| line.parse())
+        .collect::<Result<_, _>>()?;
+    RangeTreeNode::create_tree(ranges).ok_or_else(|| section.error(format!("Empty {}", header)))
+}
+
+/// Maps `x` through every map in turn, numbers outside a map's ranges
+/// keeping their value.
+pub fn feed_forward(trees: &[RangeTreeNode], x: u64) -> u64 {
+    let mut cur = x;
+    // Only kept while tracing, as this runs for every seed
+    let mut path = tracing(Level::Trace).then(|| vec![x.to_string()]);
+    for tree in trees {
+        cur = tree.map(cur).unwrap_or(cur);
+        if let Some(path) = &mut path {
+            path.push(cur.to_string());
+        }
+    }
+    if let Some(path) = path {
+        trace!(Level::Trace, "{}", path.join(" -> "));
+    }
+    cur
+}
+
+/// What the almanac numbers, in the order its maps go from one to the next.
+pub const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+fn map_header(source: &str, destination: &str) -> String {
+    format!("{}-to-{} map:", source, destination)
+}
+
+pub struct Almanac {
+    pub seed_numbers: Vec<u64>,
+    /// The maps from each of [`CATEGORIES`] to the next.
+    pub maps: Vec<RangeTreeNode>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+
+    fn parse(input: &str) -> common::Result<Self::Input> {
+        let input = Span::new(input);
+
+        let seed_numbers = input
+            .lines()
+            .next()
+            .ok_or_else(|| input.error("Missing seed numbers"))?
+            .labelled_numbers("seeds")?;
+
+        let maps = CATEGORIES
+            .windows(2)
+            .map(|pair| get_range_tree(input, &map_header(pair[0], pair[1])))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Almanac { seed_numbers, maps })
+    }
+
+    fn part_one(almanac: &Self::Input) -> common::Result<Option<String>> {
+        let part_1_answer = almanac
+            .seed_numbers
+            .iter()
+            .map(|seed| {
+                let location = feed_forward(&almanac.maps, *seed);
+                trace!(Level::Debug, "Seed {} is planted at {}", seed, location);
+                location
+            })
+            .min()
+            .ok_or_else(|| Error::Domain(String::from("No seeds to plant")))?;
+        Ok(Some(part_1_answer.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn seed_locations() {
+        let almanac = Day05::parse(EXAMPLE).unwrap();
+        let locations = almanac
+            .seed_numbers
+            .iter()
+            .map(|&seed| feed_forward(&almanac.maps, seed))
+            .collect::<Vec<_>>();
+        assert_eq!(locations, [82, 43, 86, 35]);
+    }
+
+    #[test]
+    fn seed_to_soil() {
+        let almanac = Day05::parse(EXAMPLE).unwrap();
+        let seed_to_soil = &almanac.maps[0];
+        assert_eq!(seed_to_soil.map(79), Some(81));
+        assert_eq!(seed_to_soil.map(98), Some(50));
+        assert_eq!(seed_to_soil.map(14), None);
+    }
+
+    #[test]
+    fn example_part_one() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part_one(&input).unwrap(), Some(String::from("35")));
+    }
+
+    #[test]
+    fn empty_and_overflowing_ranges_are_errors() {
+        assert!("50 98 0"
+            .parse::<MappingRange>()
+            .is_err());
+        assert!("0 18446744073709551615 2"
+            .parse::<MappingRange>()
+            .is_err());
+        assert!("18446744073709551615 0 2"
+            .parse::<MappingRange>()
+            .is_err());
+    }
+
+    #[test]
+    #[ignore = "part two is not solved yet"]
+    fn example_part_two() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part_two(&input).unwrap(), Some(String::from("46")));
+    }
+
+    /// Finds the first range containing `x` by looking through all of them.
+    fn map_by_scanning(ranges: &[MappingRange], x: u64) -> Option<u64> {
+        ranges
+            .iter()
+            .find(|range| range.query(x) == RangeQueryResult::Contains)
+            .map(|range| range.map(x))
+    }
+
+    fn mapping_range() -> impl Strategy<Value = MappingRange> {
+        (0..1000u64, 0..1000u64, 1..100u64).prop_map(|(source_start, destination_start, length)| {
+            MappingRange {
+                source_start,
+                destination_start,
+                length,
+            }
+        })
+    }
+
+    /// Ranges which share no numbers, like those of a real almanac map.
+    fn disjoint_ranges() -> impl Strategy<Value = Vec<MappingRange>> {
+        prop::collection::vec((0..50u64, 1..50u64, 0..1000u64), 1..20).prop_map(|gaps| {
+            let mut next_start = 0;
+            gaps.into_iter()
+                .map(|(gap, length, destination_start)| {
+                    let range = MappingRange {
+                        source_start: next_start + gap,
+                        destination_start,
+                        length,
+                    };
+                    next_start = range.source_start + length;
+                    range
+                })
+                .collect()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn tree_maps_like_a_scan(
+            ranges in disjoint_ranges().prop_shuffle(),
+            x in 0..1200u64,
+        ) {
+            let tree = RangeTreeNode::create_tree(ranges.clone()).unwrap();
+            prop_assert_eq!(tree.map(x), map_by_scanning(&ranges, x));
+        }
+
+        #[test]
+        fn tree_finds_overlapping_ranges(
+            ranges in prop::collection::vec(mapping_range(), 1..20),
+            x in 0..1200u64,
+        ) {
+            let tree = RangeTreeNode::create_tree(ranges.clone()).unwrap();
+            prop_assert_eq!(tree.map(x).is_some(), map_by_scanning(&ranges, x).is_some());
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use common::{Generated, GeneratingSolution, Rng};
+
+use crate::Day06;
+
+// The kerned race of part 2 must keep its time squared well inside a u64
+const MAX_RACES: usize = 4;
+
+/// Counts the hold times that beat `distance` by searching for the shortest,
+/// rather than by solving the quadratic.
+fn count_ways(time: u64, distance: u64) -> u64 {
+    let travelled = |hold: u64| hold as u128 * (time - hold) as u128;
+    let (mut low, mut high) = (0, time / 2);
+    if travelled(high) <= distance as u128 {
+        return 0;
+    }
+    while low < high {
+        let mid = (low + high) / 2;
+        if travelled(mid) > distance as u128 {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    time - 2 * low + 1
+}
+
+fn kern(numbers: &[u64]) -> u64 {
+    numbers
+        .iter()
+        .map(u64::to_string)
+        .collect::<String>()
+        .parse()
+        .expect("Kerned numbers should fit in a u64")
+}
+
+fn format_column(label: &str, numbers: &[u64]) -> String {
+    let columns = numbers
+        .iter()
+        .map(|number| format!("{:>6}", number))
+        .collect::<String>();
+    format!("{:<9}{}\n", label, columns)
+}
+
+impl GeneratingSolution for Day06 {
+    /// Generates `size` races, at most four, each of which can be won. The
+    /// kerned race of part 2 can always be won too.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let races = size.clamp(1, MAX_RACES);
+        let (times, distances) = loop {
+            let times = (0..races)
+                .map(|_| rng.between(10..=99) as u64)
+                .collect::<Vec<_>>();
+            let distances = times
+                .iter()
+                .map(|&time| {
+                    let best = time * time / 4;
+                    rng.between(best as i64 / 2..=best as i64 - 1) as u64
+                })
+                .collect::<Vec<_>>();
+            if count_ways(kern(&times), kern(&distances)) > 0 {
+                break (times, distances);
+            }
+        };
+
+        let product = times
+            .iter()
+            .zip(&distances)
+            .map(|(&time, &distance)| count_ways(time, distance))
+            .product::<u64>();
+        let kerned = count_ways(kern(&times), kern(&distances));
+        Generated {
+            input: format_column("Time:", &times) + &format_column("Distance:", &distances),
+            answers: [Some(product.to_string()), Some(kerned.to_string())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    #[test]
+    fn generated_answers_match() {
+        for seed in 0..50 {
+            let generated = Day06::generate(&mut Rng::new(seed), 4);
+            let input = Day06::parse(&generated.input).unwrap();
+            assert_eq!(Day06::part_one(&input).unwrap(), generated.answers[0]);
+            assert_eq!(Day06::part_two(&input).unwrap(), generated.answers[1]);
+        }
+    }
+}
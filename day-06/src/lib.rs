@@ -0,0 +1,257 @@
+//! Day 6: Wait For It
+
+mod generate;
+
+use common::{trace, Level, ParseError, Solution, Span};
+
+#[derive(Debug)]
+pub struct Race {
+    pub time_limit: u64,
+    pub record_distance: u64,
+}
+
+impl Race {
+    /**
+     * We can define distance travelled for any button_hold_time as:
+     *
+     *  distance = (time_limit - button_hold_time) * button_hold_time
+     *
+     * We can also define the inverse mapping, restricting button_hold_time
+     * to positive values:
+     *
+     *  button_hold_time = 0.5 * (time_limit ± sqrt(time_limit^2 - 4 * distance))
+     *
+     * Both are NaN if no hold time reaches the record. The square of the time
+     * limit is taken in a u128, as it passes a u64 for times past 2^32.
+     */
+    pub fn get_record_button_hold_time(&self) -> (f64, f64) {
+        let a = 0.5 * self.time_limit as f64;
+        let discriminant = (self.time_limit as u128 * self.time_limit as u128)
+            .checked_sub(4 * self.record_distance as u128)
+            .map_or(f64::NAN, |discriminant| discriminant as f64);
+        let b = discriminant.sqrt() * 0.5;
+        (a - b, a + b)
+    }
+
+    /// Whether holding the button for `hold` ms travels past the record.
+    fn beats_record(&self, hold: u64) -> bool {
+        hold as u128 * (self.time_limit - hold) as u128 > self.record_distance as u128
+    }
+
+    pub fn count_ways_to_beat_record(&self) -> u64 {
+        // Holding for half the time limit travels furthest
+        if !self.beats_record(self.time_limit / 2) {
+            trace!(
+                Level::Debug,
+                "Race of {} ms against a record of {} mm: no hold time travels further",
+                self.time_limit,
+                self.record_distance
+            );
+            return 0;
+        }
+        let (a, b) = self.get_record_button_hold_time();
+        // The roots lose precision once the time squared passes 2^53, so the
+        // shortest winning hold is nudged onto the exact one
+        let mut minimum_time = ((a + 1.0).floor() as u64).min(self.time_limit / 2);
+        while minimum_time > 0 && self.beats_record(minimum_time - 1) {
+            minimum_time -= 1;
+        }
+        while !self.beats_record(minimum_time) {
+            minimum_time += 1;
+        }
+        // Holding for `h` or `time_limit - h` travels as far
+        let maximum_time = self.time_limit - minimum_time;
+        let result = maximum_time - minimum_time + 1;
+        trace!(
+            Level::Debug,
+            "Race of {} ms against a record of {} mm: roots at {:.3} and {:.3} ms, so holding {} to {} ms wins in {} ways",
+            self.time_limit,
+            self.record_distance,
+            a,
+            b,
+            minimum_time,
+            maximum_time,
+            result
+        );
+        result
+    }
+}
+
+fn get_line<'a>(
+    lines: &mut impl Iterator<Item = Span<'a>>,
+    input: Span<'a>,
+) -> Result<Span<'a>, ParseError> {
+    lines
+        .next()
+        .ok_or_else(|| input.error("Unexpected end of input"))
+}
+
+/// Reads the races of part 1, one per column.
+pub fn get_races(s: &str) -> Result<Vec<Race>, ParseError> {
+    let input = Span::new(s);
+    let mut lines = input.lines();
+    let times = get_line(&mut lines, input)?.labelled_numbers("Time")?;
+    let distances = get_line(&mut lines, input)?.labelled_numbers("Distance")?;
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Race {
+            time_limit: time,
+            record_distance: distance,
+        })
+        .collect())
+}
+
+/// Reads a labelled line as one number, ignoring the spaces between digits.
+fn get_kerned_number(line: Span<'_>, label: &str) -> Result<u64, ParseError> {
+    let digits = line.labelled(label)?;
+    digits
+        .as_str()
+        .replace(' ', "")
+        .parse::<_>()
+        .map_err(|_| digits.error(format!("Expected a number, found `{}`", digits.as_str())))
+}
+
+/// Reads the single race of part 2, ignoring the spaces between digits.
+pub fn get_race_part_2(s: &str) -> Result<Race, ParseError> {
+    let input = Span::new(s);
+    let mut lines = input.lines();
+    let time = get_kerned_number(get_line(&mut lines, input)?, "Time")?;
+    let distance = get_kerned_number(get_line(&mut lines, input)?, "Distance")?;
+    Ok(Race {
+        time_limit: time,
+        record_distance: distance,
+    })
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = (Vec<Race>, Race);
+
+    fn parse(input: &str) -> common::Result<Self::Input> {
+        Ok((get_races(input)?, get_race_part_2(input)?))
+    }
+
+    fn part_one((races, _): &Self::Input) -> common::Result<Option<String>> {
+        let part_1_answer = races
+            .iter()
+            .map(|r| r.count_ways_to_beat_record())
+            .product::<u64>();
+        Ok(Some(part_1_answer.to_string()))
+    }
+
+    fn part_two((_, race): &Self::Input) -> common::Result<Option<String>> {
+        let part_2_answer = race.count_ways_to_beat_record();
+        Ok(Some(part_2_answer.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::capture_trace;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn ways_to_beat_each_record() {
+        let races = get_races(EXAMPLE).unwrap();
+        let ways = races
+            .iter()
+            .map(|race| race.count_ways_to_beat_record())
+            .collect::<Vec<_>>();
+        assert_eq!(ways, [4, 8, 9]);
+    }
+
+    #[test]
+    fn root_bounds_are_explained() {
+        let races = get_races(EXAMPLE).unwrap();
+        let (ways, records) = capture_trace(Level::Debug, || races[0].count_ways_to_beat_record());
+        assert_eq!(ways, 4);
+        assert_eq!(
+            records[0].message,
+            "Race of 7 ms against a record of 9 mm: roots at 1.697 and 5.303 ms, so holding 2 to 5 ms wins in 4 ways"
+        );
+    }
+
+    #[test]
+    fn kerned_race() {
+        let race = get_race_part_2(EXAMPLE).unwrap();
+        assert_eq!(race.count_ways_to_beat_record(), 71503);
+    }
+
+    #[test]
+    fn example_part_one() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part_one(&input).unwrap(), Some(String::from("288")));
+    }
+
+    #[test]
+    fn example_part_two() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            Day06::part_two(&input).unwrap(),
+            Some(String::from("71503"))
+        );
+    }
+
+    /// Counts the ways to beat the record by trying every hold time.
+    fn count_by_trying_every_hold_time(race: &Race) -> u64 {
+        (0..=race.time_limit)
+            .filter(|hold| hold * (race.time_limit - hold) > race.record_distance)
+            .count() as u64
+    }
+
+    #[test]
+    fn long_races_do_not_overflow() {
+        let input = Day06::parse("Time: 9999999999\nDistance: 9999999999\n").unwrap();
+        assert_eq!(
+            Day06::part_two(&input).unwrap(),
+            Some(String::from("9999999996"))
+        );
+        let race = Race {
+            time_limit: 9_999_999_999,
+            record_distance: 18_000_000_000_000_000_000,
+        };
+        assert_eq!(race.count_ways_to_beat_record(), 5_291_502_620);
+        let race = Race {
+            time_limit: 8_000_000_000,
+            record_distance: 16_000_000_000_000_000_000,
+        };
+        assert_eq!(race.count_ways_to_beat_record(), 0);
+        let race = Race {
+            time_limit: 2,
+            record_distance: 5,
+        };
+        assert!(race
+            .get_record_button_hold_time()
+            .0
+            .is_nan());
+    }
+
+    // Records past a quarter of the time squared cannot be beaten, so drawing
+    // beyond it would only test the early return
+    fn race() -> impl Strategy<Value = Race> {
+        (1..100_000u64).prop_flat_map(|time_limit| {
+            (0..=time_limit * time_limit / 4).prop_map(move |record_distance| Race {
+                time_limit,
+                record_distance,
+            })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn roots_count_like_trying_every_hold_time(race in race()) {
+            prop_assert_eq!(
+                race.count_ways_to_beat_record(),
+                count_by_trying_every_hold_time(&race)
+            );
+        }
+    }
+}
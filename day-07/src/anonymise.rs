@@ -0,0 +1,117 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use common::{AnonymisingSolution, Rng};
+
+use crate::{generate::CARDS, Day07, Hand, HandType, Part1Card};
+
+/// How many of each card a hand of `hand_type` holds, without jokers.
+fn counts(hand_type: &HandType) -> &'static [usize] {
+    match hand_type {
+        HandType::FiveOfAKind => &[5],
+        HandType::FourOfAKind => &[4, 1],
+        HandType::FullHouse => &[3, 2],
+        HandType::ThreeOfAKind => &[3, 1, 1],
+        HandType::TwoPair => &[2, 2, 1],
+        HandType::OnePair => &[2, 1, 1, 1],
+        HandType::HighCard => &[1, 1, 1, 1, 1],
+    }
+}
+
+/// A random hand of `hand_type` without jokers, with a bid of 0.
+fn random_hand(rng: &mut Rng, hand_type: &HandType) -> Hand<5, Part1Card> {
+    let mut labels = CARDS.to_vec();
+    rng.shuffle(&mut labels);
+    let mut cards = counts(hand_type)
+        .iter()
+        .zip(labels)
+        .flat_map(|(&count, label)| std::iter::repeat_n(label, count))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut cards);
+    format!("{} 0", String::from_utf8_lossy(&cards))
+        .parse()
+        .expect("Random hands are valid")
+}
+
+impl AnonymisingSolution for Day07 {
+    /// Deals every distinct hand a new one of the same type, such that hands
+    /// of a type rank in the same order as before. Bids stay on their line,
+    /// so part 1 keeps its answer. Jokers move about, so part 2 does not.
+    fn anonymise(input: &str, rng: &mut Rng) -> common::Result<String> {
+        let hands: Vec<Hand<5, Part1Card>> = common::parse_lines(input)?;
+
+        let mut by_type = BTreeMap::<HandType, Vec<&Hand<5, Part1Card>>>::new();
+        let mut seen = HashSet::new();
+        for hand in &hands {
+            if seen.insert(hand.cards()) {
+                by_type
+                    .entry(hand.hand_type().clone())
+                    .or_default()
+                    .push(hand);
+            }
+        }
+
+        let mut dealt = HashMap::new();
+        for (hand_type, mut originals) in by_type {
+            let mut cards = HashSet::new();
+            let mut replacements = vec![];
+            while replacements.len() < originals.len() {
+                let replacement = random_hand(rng, &hand_type);
+                if cards.insert(*replacement.cards()) {
+                    replacements.push(replacement);
+                }
+            }
+            originals.sort();
+            replacements.sort();
+            for (original, replacement) in originals.into_iter().zip(replacements) {
+                dealt.insert(original.cards(), replacement);
+            }
+        }
+
+        Ok(hands
+            .iter()
+            .map(|hand| {
+                let cards = dealt[hand.cards()]
+                    .cards()
+                    .iter()
+                    .map(Part1Card::to_string)
+                    .collect::<String>();
+                format!("{} {}\n", cards, hand.bid())
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn ranks_are_kept() {
+        let hands = Day07::parse(EXAMPLE).unwrap();
+        for seed in 0..10 {
+            let anonymised = Day07::anonymise(EXAMPLE, &mut Rng::new(seed)).unwrap();
+            let relabelled = Day07::parse(&anonymised).unwrap();
+            assert_ne!(anonymised, EXAMPLE);
+            assert_eq!(
+                Day07::part_one(&relabelled).unwrap(),
+                Day07::part_one(&hands).unwrap()
+            );
+            for (hand, relabelled) in hands.0.iter().zip(&relabelled.0) {
+                assert_eq!(relabelled.hand_type(), hand.hand_type());
+                assert_eq!(relabelled.bid(), hand.bid());
+            }
+        }
+    }
+
+    #[test]
+    fn repeated_hands_stay_repeated() {
+        let anonymised = Day07::anonymise("AAAAA 1\n23456 2\nAAAAA 3\n", &mut Rng::new(0)).unwrap();
+        let lines = anonymised.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0][..5], lines[2][..5]);
+        assert_ne!(lines[0][..5], lines[1][..5]);
+    }
+}
@@ -0,0 +1,108 @@
+use common::{Generated, GeneratingSolution, Rng};
+
+use crate::Day07;
+
+pub(crate) const CARDS: &[u8] = b"23456789TJQKA";
+const CARDS_WITH_JOKERS: &[u8] = b"J23456789TQKA";
+
+// Leaves plenty of room below the 13^5 distinct hands
+const MAX_HANDS: usize = 100_000;
+
+/// Ranks a hand's type from 0 for a high card to 6 for five of a kind,
+/// counting each joker towards the most common other card.
+fn rank_type(cards: &[u8], jokers: bool) -> u8 {
+    let mut counts = [0u8; 256];
+    let mut joker_count = 0;
+    for &card in cards {
+        if jokers && card == b'J' {
+            joker_count += 1;
+        } else {
+            counts[card as usize] += 1;
+        }
+    }
+    let mut counts = counts
+        .into_iter()
+        .filter(|&count| count > 0)
+        .collect::<Vec<_>>();
+    counts.sort_by(|a, b| b.cmp(a));
+    match counts.first_mut() {
+        Some(most) => *most += joker_count,
+        None => counts.push(joker_count),
+    }
+    match counts[..] {
+        [5] => 6,
+        [4, 1] => 5,
+        [3, 2] => 4,
+        [3, ..] => 3,
+        [2, 2, 1] => 2,
+        [2, ..] => 1,
+        _ => 0,
+    }
+}
+
+/// Sums each bid multiplied by its rank, ordering hands by type and then by
+/// the strength of each card in turn.
+fn total_winnings(hands: &[(Vec<u8>, u64)], jokers: bool) -> u64 {
+    let order = if jokers { CARDS_WITH_JOKERS } else { CARDS };
+    let mut keyed = hands
+        .iter()
+        .map(|(cards, bid)| {
+            let strengths = cards
+                .iter()
+                .map(|card| order.iter().position(|c| c == card))
+                .collect::<Vec<_>>();
+            ((rank_type(cards, jokers), strengths), *bid)
+        })
+        .collect::<Vec<_>>();
+    keyed.sort();
+    keyed
+        .iter()
+        .enumerate()
+        .map(|(i, (_, bid))| (i as u64 + 1) * bid)
+        .sum()
+}
+
+impl GeneratingSolution for Day07 {
+    /// Generates `size` distinct hands, at most 100000, with bids up to 1000.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut seen = std::collections::HashSet::new();
+        let mut hands = vec![];
+        while hands.len() < size.min(MAX_HANDS) {
+            let cards = (0..5)
+                .map(|_| *rng.pick(CARDS))
+                .collect::<Vec<_>>();
+            if seen.insert(cards.clone()) {
+                hands.push((cards, rng.between(1..=1000) as u64));
+            }
+        }
+
+        let input = hands
+            .iter()
+            .map(|(cards, bid)| format!("{} {}\n", String::from_utf8_lossy(cards), bid))
+            .collect();
+        Generated {
+            input,
+            answers: [
+                Some(total_winnings(&hands, false).to_string()),
+                Some(total_winnings(&hands, true).to_string()),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    #[test]
+    fn generated_answers_match() {
+        for seed in 0..20 {
+            let generated = Day07::generate(&mut Rng::new(seed), 200);
+            let input = Day07::parse(&generated.input).unwrap();
+            assert_eq!(Day07::part_one(&input).unwrap(), generated.answers[0]);
+            assert_eq!(Day07::part_two(&input).unwrap(), generated.answers[1]);
+        }
+    }
+}
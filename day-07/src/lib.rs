@@ -0,0 +1,465 @@
+//! Day 7: Camel Cards
+
+mod anonymise;
+mod generate;
+
+use common::{trace, Level, Line, ParseError, Solution, Span, StreamingSolution};
+use core::panic;
+use itertools::Itertools;
+use std::{collections::HashMap, fmt, str::FromStr};
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    /// Types a hand from how many of each card it holds, most common first.
+    fn from_counts(counts: &[u8]) -> HandType {
+        match counts {
+            [5, ..] => HandType::FiveOfAKind,
+            [4, ..] => HandType::FourOfAKind,
+            [3, 2, ..] => HandType::FullHouse,
+            [3, ..] => HandType::ThreeOfAKind,
+            [2, 2, ..] => HandType::TwoPair,
+            [2, ..] => HandType::OnePair,
+            [] => panic!("Cannot determine hand type from empty hand"),
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+impl fmt::Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HandType::HighCard => "high card",
+            HandType::OnePair => "one pair",
+            HandType::TwoPair => "two pair",
+            HandType::ThreeOfAKind => "three of a kind",
+            HandType::FullHouse => "full house",
+            HandType::FourOfAKind => "four of a kind",
+            HandType::FiveOfAKind => "five of a kind",
+        })
+    }
+}
+
+/// How many of each card `cards` holds, most common first.
+fn count_cards<CardType: std::hash::Hash + Eq + Copy>(cards: &[CardType]) -> Vec<u8> {
+    let mut count_per_card: HashMap<CardType, u8> = HashMap::default();
+    for card in cards {
+        count_per_card
+            .entry(*card)
+            .and_modify(|count| *count += 1)
+            .or_insert(1);
+    }
+    let mut counts = count_per_card
+        .into_values()
+        .collect::<Vec<_>>();
+    counts.sort();
+    counts.reverse();
+    counts
+}
+
+pub trait GetHandType
+where
+    Self: Sized,
+{
+    fn get_hand_type(cards: &[Self]) -> HandType;
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy, Debug)]
+pub enum Part1Card {
+    Number(u8),
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl FromStr for Part1Card {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(Part1Card::Ace),
+            "K" => Ok(Part1Card::King),
+            "Q" => Ok(Part1Card::Queen),
+            "J" => Ok(Part1Card::Jack),
+            "T" => Ok(Part1Card::Number(10)),
+            _ => {
+                let parse = s.parse::<u8>();
+                let number = parse
+                    .map_err(|_| ParseError::new(format!("Cannot get card number from {}", s)))?;
+                if !(2..=10).contains(&number) {
+                    Err(ParseError::new(format!("Invalid card number {}", s)))
+                } else {
+                    Ok(Part1Card::Number(number))
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Part1Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part1Card::Number(10) => write!(f, "T"),
+            Part1Card::Number(number) => write!(f, "{}", number),
+            Part1Card::Jack => write!(f, "J"),
+            Part1Card::Queen => write!(f, "Q"),
+            Part1Card::King => write!(f, "K"),
+            Part1Card::Ace => write!(f, "A"),
+        }
+    }
+}
+
+impl GetHandType for Part1Card {
+    fn get_hand_type(cards: &[Self]) -> HandType {
+        HandType::from_counts(&count_cards(cards))
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Hash, Clone, Copy, Debug)]
+pub enum Part2Card {
+    Joker,
+    Number(u8),
+    Queen,
+    King,
+    Ace,
+}
+
+impl FromStr for Part2Card {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(Part2Card::Ace),
+            "K" => Ok(Part2Card::King),
+            "Q" => Ok(Part2Card::Queen),
+            "J" => Ok(Part2Card::Joker),
+            "T" => Ok(Part2Card::Number(10)),
+            _ => {
+                let parse = s.parse::<u8>();
+                let number = parse
+                    .map_err(|_| ParseError::new(format!("Cannot get card number from {}", s)))?;
+                if !(2..=10).contains(&number) {
+                    Err(ParseError::new(format!("Invalid card number {}", s)))
+                } else {
+                    Ok(Part2Card::Number(number))
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Part2Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part2Card::Joker => write!(f, "J"),
+            Part2Card::Number(10) => write!(f, "T"),
+            Part2Card::Number(number) => write!(f, "{}", number),
+            Part2Card::Queen => write!(f, "Q"),
+            Part2Card::King => write!(f, "K"),
+            Part2Card::Ace => write!(f, "A"),
+        }
+    }
+}
+
+impl Part2Card {
+    /// Every card a joker can stand in for.
+    pub fn joker_replacements() -> impl Iterator<Item = Part2Card> {
+        [
+            Part2Card::Number(2),
+            Part2Card::Number(3),
+            Part2Card::Number(4),
+            Part2Card::Number(5),
+            Part2Card::Number(6),
+            Part2Card::Number(7),
+            Part2Card::Number(8),
+            Part2Card::Number(9),
+            Part2Card::Number(10),
+            Part2Card::Queen,
+            Part2Card::King,
+            Part2Card::Ace,
+        ]
+        .into_iter()
+    }
+
+    /// Every hand the cards can become with their jokers replaced.
+    pub fn get_possible_hands(cards: &[Self]) -> Vec<Vec<Self>> {
+        let index_of_joker = cards
+            .iter()
+            .position(|c| *c == Part2Card::Joker);
+        if let Some(index) = index_of_joker {
+            let possible_replacements = Part2Card::joker_replacements();
+            let mut results = vec![];
+            for card in possible_replacements {
+                let new_cards = [&cards[..index], &[card], &cards[index + 1..]].concat();
+                let sub_results = Self::get_possible_hands(&new_cards);
+                results.extend(sub_results)
+            }
+            results
+        } else {
+            vec![cards.to_vec()]
+        }
+    }
+}
+
+impl GetHandType for Part2Card {
+    /// Jokers always do best standing in for the most common other card, so
+    /// this never tries the hands of [`Part2Card::get_possible_hands`].
+    fn get_hand_type(cards: &[Self]) -> HandType {
+        let others = cards
+            .iter()
+            .filter(|&&card| card != Part2Card::Joker)
+            .copied()
+            .collect::<Vec<_>>();
+        let jokers = (cards.len() - others.len()) as u8;
+        let mut counts = count_cards(&others);
+        match counts.first_mut() {
+            Some(most_common) => *most_common += jokers,
+            None => counts.push(jokers),
+        }
+        HandType::from_counts(&counts)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Hand<const SIZE: usize, CardType> {
+    hand_type: HandType,
+    cards: [CardType; SIZE],
+    bid: u16,
+}
+
+impl<const SIZE: usize, CardType> Hand<SIZE, CardType> {
+    pub fn hand_type(&self) -> &HandType {
+        &self.hand_type
+    }
+
+    pub fn cards(&self) -> &[CardType; SIZE] {
+        &self.cards
+    }
+
+    pub fn bid(&self) -> u16 {
+        self.bid
+    }
+}
+
+impl<const SIZE: usize, CardType: fmt::Display> fmt::Display for Hand<SIZE, CardType> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for card in &self.cards {
+            write!(f, "{}", card)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const SIZE: usize, CardType: PartialOrd> PartialOrd for Hand<SIZE, CardType> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.hand_type
+            .partial_cmp(&other.hand_type)
+            .filter(|ord| ord.is_ne())
+            .or_else(|| {
+                self.cards
+                    .iter()
+                    .zip(other.cards.iter())
+                    .filter_map(|(a, b)| a.partial_cmp(b))
+                    .find(|ord| ord.is_ne())
+            })
+    }
+}
+
+impl<const SIZE: usize, CardType: PartialOrd + Eq> Ord for Hand<SIZE, CardType> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match self.partial_cmp(other) {
+            Some(ordering) => ordering,
+            None => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+impl<const SIZE: usize, CardType> FromStr for Hand<SIZE, CardType>
+where
+    CardType: FromStr<Err = ParseError> + std::hash::Hash + GetHandType,
+{
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (cards_str, bid_str) = Span::new(s).split_once(" ")?;
+        let cards = cards_str
+            .chars()
+            .map(|card| card.parse())
+            .collect::<Result<Vec<CardType>, _>>()?;
+        let cards: [CardType; SIZE] = cards
+            .try_into()
+            .map_err(|_| cards_str.error(format!("Expected {} cards", SIZE)))?;
+        let hand_type = CardType::get_hand_type(cards.as_slice());
+        let bid = bid_str.value()?;
+        Ok(Hand {
+            hand_type,
+            cards,
+            bid,
+        })
+    }
+}
+
+/// Sums each hand's bid multiplied by its rank, the weakest hand ranking 1.
+pub fn get_total_bid<const SIZE: usize, CardType: PartialOrd + Eq + FromStr + fmt::Display>(
+    hands: &[Hand<SIZE, CardType>],
+) -> u128 {
+    hands
+        .iter()
+        .sorted()
+        .enumerate()
+        .map(|(i, e)| (i + 1, e))
+        .map(|(rank, hand)| {
+            trace!(
+                Level::Debug,
+                "{} is {}, ranking {} for {} x {}",
+                hand,
+                hand.hand_type,
+                rank,
+                rank,
+                hand.bid
+            );
+            rank as u128 * hand.bid as u128
+        })
+        .sum::<_>()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = (Vec<Hand<5, Part1Card>>, Vec<Hand<5, Part2Card>>);
+
+    fn parse(input: &str) -> common::Result<Self::Input> {
+        let hands_1: Vec<Hand<5, Part1Card>> = common::parse_lines(input)?;
+        let hands_2: Vec<Hand<5, Part2Card>> = common::parse_lines(input)?;
+        Ok((hands_1, hands_2))
+    }
+
+    fn part_one((hands_1, _): &Self::Input) -> common::Result<Option<String>> {
+        let part_1_answer = get_total_bid(hands_1);
+        Ok(Some(part_1_answer.to_string()))
+    }
+
+    fn part_two((_, hands_2): &Self::Input) -> common::Result<Option<String>> {
+        let part_2_answer = get_total_bid(hands_2);
+        Ok(Some(part_2_answer.to_string()))
+    }
+}
+
+// Ranking needs every hand, so streaming only saves holding the text.
+impl StreamingSolution for Day07 {
+    fn solve_lines(
+        lines: &mut dyn Iterator<Item = common::Result<Line>>,
+    ) -> common::Result<[Option<String>; 2]> {
+        let mut hands_1: Vec<Hand<5, Part1Card>> = vec![];
+        let mut hands_2: Vec<Hand<5, Part2Card>> = vec![];
+        for line in lines {
+            let line = line?;
+            hands_1.push(line.parse()?);
+            hands_2.push(line.parse()?);
+        }
+        Ok([
+            Some(get_total_bid(&hands_1).to_string()),
+            Some(get_total_bid(&hands_2).to_string()),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{capture_trace, Lines};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn hand_classifications_are_explained() {
+        let hands: Vec<Hand<5, Part2Card>> = common::parse_lines(EXAMPLE).unwrap();
+        let (_, records) = capture_trace(Level::Debug, || get_total_bid(&hands));
+        let messages = records
+            .iter()
+            .map(|record| record.message.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                "32T3K is one pair, ranking 1 for 1 x 765",
+                "KK677 is two pair, ranking 2 for 2 x 28",
+                "T55J5 is four of a kind, ranking 3 for 3 x 684",
+                "QQQJA is four of a kind, ranking 4 for 4 x 483",
+                "KTJJT is four of a kind, ranking 5 for 5 x 220",
+            ]
+        );
+    }
+
+    #[test]
+    fn total_bid_without_jokers() {
+        let hands: Vec<Hand<5, Part1Card>> = common::parse_lines(EXAMPLE).unwrap();
+        assert_eq!(get_total_bid(&hands), 6440);
+    }
+
+    #[test]
+    fn total_bid_with_jokers() {
+        let hands: Vec<Hand<5, Part2Card>> = common::parse_lines(EXAMPLE).unwrap();
+        assert_eq!(get_total_bid(&hands), 5905);
+    }
+
+    #[test]
+    fn example_part_one() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part_one(&input).unwrap(), Some(String::from("6440")));
+    }
+
+    #[test]
+    fn example_part_two() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part_two(&input).unwrap(), Some(String::from("5905")));
+    }
+
+    #[test]
+    fn streamed_example() {
+        let answers = Day07::solve_lines(&mut Lines::new(EXAMPLE.as_bytes(), "example")).unwrap();
+        assert_eq!(
+            answers,
+            [Some(String::from("6440")), Some(String::from("5905"))]
+        );
+    }
+
+    /// Types a hand by trying every card its jokers could stand in for.
+    fn type_by_substituting_jokers(cards: &[Part2Card]) -> HandType {
+        Part2Card::get_possible_hands(cards)
+            .iter()
+            .map(|hand| HandType::from_counts(&count_cards(hand)))
+            .max()
+            .unwrap()
+    }
+
+    fn part_2_card() -> impl Strategy<Value = Part2Card> {
+        // Jokers come up more often than in a real deck, without making every
+        // hand too slow to substitute
+        prop_oneof![
+            1 => Just(Part2Card::Joker),
+            3 => prop::sample::select(Part2Card::joker_replacements().collect::<Vec<_>>()),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn jokers_type_like_substituting_them(cards in prop::array::uniform5(part_2_card())) {
+            prop_assert_eq!(
+                Part2Card::get_hand_type(&cards),
+                type_by_substituting_jokers(&cards)
+            );
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{AnonymisingSolution, Rng, Span};
+
+use crate::{parse_input, Day08, Node};
+
+const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Where the walk of part 1 starts and ends, which keep their names.
+const FIXED: [&str; 2] = ["AAA", "ZZZ"];
+
+/// A new name as long as `name`, ending in A or Z if it does, since the
+/// ghosts of part 2 walk from nodes ending in A to nodes ending in Z.
+fn rename(rng: &mut Rng, name: &str) -> String {
+    let last = match name.chars().last() {
+        Some(last @ ('A' | 'Z')) => last,
+        _ => loop {
+            let letter = *rng.pick(LETTERS) as char;
+            if letter != 'A' && letter != 'Z' {
+                break letter;
+            }
+        },
+    };
+    let mut renamed = (1..name.chars().count())
+        .map(|_| *rng.pick(LETTERS) as char)
+        .collect::<String>();
+    renamed.push(last);
+    renamed
+}
+
+/// Gives every name a new one of its own, keeping those of [`FIXED`].
+struct Renames<'a> {
+    names: HashMap<&'a str, String>,
+    taken: HashSet<String>,
+}
+
+impl<'a> Renames<'a> {
+    fn new() -> Self {
+        Renames {
+            names: HashMap::new(),
+            taken: FIXED
+                .iter()
+                .map(|&name| String::from(name))
+                .collect(),
+        }
+    }
+
+    fn get(&mut self, rng: &mut Rng, name: &'a str) -> String {
+        if FIXED.contains(&name) {
+            return String::from(name);
+        }
+        if let Some(renamed) = self.names.get(name) {
+            return renamed.clone();
+        }
+        let renamed = loop {
+            let renamed = rename(rng, name);
+            if self.taken.insert(renamed.clone()) {
+                break renamed;
+            }
+        };
+        self.names.insert(name, renamed.clone());
+        renamed
+    }
+}
+
+impl AnonymisingSolution for Day08 {
+    /// Gives every node but `AAA` and `ZZZ` a new name ending like the old
+    /// one, and lists the nodes in a new order. Half the time it also swaps
+    /// left for right, in the instructions and in every node. Both walks
+    /// keep their length.
+    fn anonymise(input: &str, rng: &mut Rng) -> common::Result<String> {
+        // Checks the whole input, so that what follows can take it apart
+        parse_input(input)?;
+        let mut sections = Span::new(input).sections();
+        let instructions = sections
+            .next()
+            .map_or("", |instructions| instructions.as_str());
+        let nodes = sections
+            .next()
+            .map_or(Ok(vec![]), |nodes| {
+                nodes
+                    .lines()
+                    .map(|line| line.parse::<Node>())
+                    .collect()
+            })?;
+
+        let mirrored = rng.chance(1, 2);
+        let instructions = instructions
+            .chars()
+            .map(|instruction| match (instruction, mirrored) {
+                ('L', true) => 'R',
+                ('R', true) => 'L',
+                (instruction, _) => instruction,
+            })
+            .collect::<String>();
+        let mut renames = Renames::new();
+        let mut lines = nodes
+            .iter()
+            .map(|node| {
+                let (left, right) = if mirrored {
+                    (&node.right, &node.left)
+                } else {
+                    (&node.left, &node.right)
+                };
+                format!(
+                    "{} = ({}, {})\n",
+                    renames.get(rng, &node.id),
+                    renames.get(rng, left),
+                    renames.get(rng, right)
+                )
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut lines);
+        Ok(format!("{}\n\n{}", instructions, lines.concat()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_3: &str = include_str!("../example-3.txt");
+
+    #[test]
+    fn walks_keep_their_length() {
+        for seed in 0..10 {
+            let anonymised = Day08::anonymise(EXAMPLE, &mut Rng::new(seed)).unwrap();
+            let (instructions, network) = parse_input(&anonymised).unwrap();
+            assert_eq!(
+                network
+                    .measure_walk(&instructions)
+                    .unwrap(),
+                2
+            );
+            assert!(!anonymised.contains("BBB = "));
+            assert!(anonymised.contains("ZZZ = (ZZZ, ZZZ)"));
+
+            let anonymised = Day08::anonymise(EXAMPLE_3, &mut Rng::new(seed)).unwrap();
+            let (instructions, network) = parse_input(&anonymised).unwrap();
+            assert_eq!(network.measure_ghost_walk(&instructions), Some(6));
+        }
+    }
+
+    #[test]
+    fn errors_are_located_in_the_original() {
+        let error = Day08::anonymise("LR\n\nAAA = (BBB, CCC\n", &mut Rng::new(0)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Parse error at 3:8: Expected `)` at the end"
+        );
+    }
+}
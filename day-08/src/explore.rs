@@ -0,0 +1,98 @@
+use common::{Error, ExploringSolution, ReplCommand, Result, Span};
+
+use crate::{Day08, Instruction, Network};
+
+fn missing_node(id: &str) -> Error {
+    Error::Domain(format!("Node {} does not exist", id))
+}
+
+fn node(network: &Network, args: Span<'_>) -> Result<String> {
+    let [id] = args.split_words()?;
+    let id = id.as_str();
+    match (network.go_left_from(id), network.go_right_from(id)) {
+        (Some(left), Some(right)) => Ok(format!("{} = ({}, {})", id, left, right)),
+        _ => Err(missing_node(id)),
+    }
+}
+
+fn walk(instructions: &[Instruction], network: &Network, args: Span<'_>) -> Result<String> {
+    let [start, steps] = args.split_words()?;
+    let steps = steps.value::<usize>()?;
+    if steps > 0 && instructions.is_empty() {
+        return Err(Error::Domain(String::from("No instructions to follow")));
+    }
+    let mut node_id = start.as_str();
+    for instruction in instructions.iter().cycle().take(steps) {
+        node_id = match instruction {
+            Instruction::Left => network.go_left_from(node_id),
+            Instruction::Right => network.go_right_from(node_id),
+        }
+        .ok_or_else(|| missing_node(node_id))?;
+    }
+    Ok(format!(
+        "{} reaches {} in {} steps",
+        start.as_str(),
+        node_id,
+        steps
+    ))
+}
+
+impl ExploringSolution for Day08 {
+    const COMMANDS: &'static [ReplCommand] = &[
+        ReplCommand {
+            name: "node",
+            args: "<node>",
+            help: "Show where a node leads left and right",
+        },
+        ReplCommand {
+            name: "walk",
+            args: "<node> <steps>",
+            help: "Follow the instructions from a node for a number of steps",
+        },
+    ];
+
+    fn explore(
+        (instructions, network): &Self::Input,
+        command: &str,
+        args: Span<'_>,
+    ) -> Option<Result<String>> {
+        match command {
+            "node" => Some(node(network, args)),
+            "walk" => Some(walk(instructions, network, args)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    const EXAMPLE_2: &str = include_str!("../example-2.txt");
+
+    fn run(line: &str) -> Result<String> {
+        let input = Day08::parse(EXAMPLE_2).unwrap();
+        let (command, args) = Span::new(line)
+            .split_first_word()
+            .unwrap();
+        Day08::explore(&input, command.as_str(), args).unwrap()
+    }
+
+    #[test]
+    fn nodes() {
+        assert_eq!(run("node AAA").unwrap(), "AAA = (BBB, BBB)");
+        assert_eq!(
+            run("node XYZ").unwrap_err().to_string(),
+            "Node XYZ does not exist"
+        );
+    }
+
+    #[test]
+    fn walks() {
+        assert_eq!(run("walk AAA 0").unwrap(), "AAA reaches AAA in 0 steps");
+        assert_eq!(run("walk AAA 6").unwrap(), "AAA reaches ZZZ in 6 steps");
+        assert_eq!(run("walk BBB 2").unwrap(), "BBB reaches BBB in 2 steps");
+    }
+}
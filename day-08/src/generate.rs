@@ -0,0 +1,95 @@
+use common::{Generated, GeneratingSolution, Rng};
+
+use crate::Day08;
+
+// Only the start and end nodes may end in `A` or `Z`
+const LAST_LETTERS: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY";
+
+const MAX_STEPS: usize = 8000;
+
+/// Every id that is neither a start nor an end node, in a random order.
+fn shuffled_ids(rng: &mut Rng) -> Vec<String> {
+    let mut ids = vec![];
+    for a in b'A'..=b'Z' {
+        for b in b'A'..=b'Z' {
+            for &c in LAST_LETTERS {
+                ids.push(String::from_utf8(vec![a, b, c]).unwrap());
+            }
+        }
+    }
+    rng.shuffle(&mut ids);
+    ids
+}
+
+impl GeneratingSolution for Day08 {
+    /// Generates a network whose walk from `AAA` reaches `ZZZ` after `size`
+    /// steps, at most 8000, among about as many decoy nodes. `AAA` is the
+    /// only start node, so the ghost walk of part 2 takes as many steps, but
+    /// part 2 has no answer until it is solved.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let steps = size.clamp(1, MAX_STEPS);
+        let instructions = (0..rng.between(1..=300))
+            .map(|_| *rng.pick(b"LR") as char)
+            .collect::<String>();
+
+        let mut ids = shuffled_ids(rng);
+        ids.truncate(2 * steps - 1);
+        let mut path = vec![String::from("AAA")];
+        path.extend_from_slice(&ids[..steps - 1]);
+        path.push(String::from("ZZZ"));
+        let everywhere = [&ids[..], &path[..]].concat();
+
+        // Path nodes lead on to the next one in the instructed direction and
+        // anywhere in the other
+        let mut nodes = path
+            .windows(2)
+            .zip(instructions.chars().cycle())
+            .map(|(pair, instruction)| {
+                let elsewhere = rng.pick(&everywhere);
+                match instruction {
+                    'L' => (&pair[0], &pair[1], elsewhere),
+                    _ => (&pair[0], elsewhere, &pair[1]),
+                }
+            })
+            .collect::<Vec<_>>();
+        for id in &ids[steps - 1..] {
+            nodes.push((id, rng.pick(&everywhere), rng.pick(&everywhere)));
+        }
+        nodes.push((&path[steps], &path[steps], &path[steps]));
+        rng.shuffle(&mut nodes);
+
+        let mut input = format!("{}\n\n", instructions);
+        for (id, left, right) in nodes {
+            input += &format!("{} = ({}, {})\n", id, left, right);
+        }
+        Generated {
+            input,
+            answers: [Some(steps.to_string()), None],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+    use crate::parse_input;
+
+    #[test]
+    fn generated_answers_match() {
+        for seed in 0..20 {
+            let generated = Day08::generate(&mut Rng::new(seed), 500);
+            let input = Day08::parse(&generated.input).unwrap();
+            assert_eq!(Day08::part_one(&input).unwrap(), generated.answers[0]);
+
+            let (instructions, network) = parse_input(&generated.input).unwrap();
+            let ghost_steps = network.measure_ghost_walk(&instructions);
+            assert_eq!(
+                ghost_steps.map(|steps| steps.to_string()),
+                generated.answers[0]
+            );
+            assert_eq!(generated.answers[1], None);
+        }
+    }
+}
@@ -0,0 +1,252 @@
+//! Day 8: Haunted Wasteland
+
+mod anonymise;
+mod explore;
+mod generate;
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use common::{trace, Error, Level, ParseError, Solution, Span};
+
+#[derive(Clone)]
+pub struct Node {
+    pub id: String,
+    pub left: String,
+    pub right: String,
+}
+
+impl FromStr for Node {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (id, pair) = Span::new(s).split_once(" = ")?;
+        let (left, right) = pair
+            .strip_prefix("(")?
+            .strip_suffix(")")?
+            .split_once(", ")?;
+        Ok(Node {
+            id: String::from(id.as_str()),
+            left: String::from(left.as_str()),
+            right: String::from(right.as_str()),
+        })
+    }
+}
+
+pub enum Instruction {
+    Left,
+    Right,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "L" => Ok(Self::Left),
+            "R" => Ok(Self::Right),
+            _ => Err(ParseError::new(format!("Invalid instruction {:?}", s))),
+        }
+    }
+}
+
+pub struct Network {
+    nodes_by_id: HashMap<String, Node>,
+}
+
+impl From<&[Node]> for Network {
+    fn from(nodes: &[Node]) -> Self {
+        Network {
+            nodes_by_id: nodes
+                .iter()
+                .map(|node| (node.id.clone(), node.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl Network {
+    pub fn go_left_from(&self, node_id: &str) -> Option<&String> {
+        self.nodes_by_id
+            .get(node_id)
+            .map(|node| &node.left)
+    }
+
+    pub fn go_right_from(&self, node_id: &str) -> Option<&String> {
+        self.nodes_by_id
+            .get(node_id)
+            .map(|node| &node.right)
+    }
+
+    /// Counts the steps from `AAA` to `ZZZ`.
+    pub fn measure_walk(&self, instructions: &[Instruction]) -> common::Result<usize> {
+        if instructions.is_empty() {
+            return Err(Error::Domain(String::from("No instructions to follow")));
+        }
+        let repeating_instructions = instructions.iter().cycle();
+        let mut visited = HashSet::new();
+        let mut cur_node_id = &String::from("AAA");
+        for (num_steps, instruction) in repeating_instructions.enumerate() {
+            if cur_node_id == "ZZZ" {
+                trace!(
+                    Level::Info,
+                    "Reached ZZZ after {} steps, going through the {} instructions {} times",
+                    num_steps,
+                    instructions.len(),
+                    num_steps.div_ceil(instructions.len())
+                );
+                return Ok(num_steps);
+            }
+            // Being back on a node at the same point in the instructions means we're in a loop
+            if !visited.insert((cur_node_id, num_steps % instructions.len())) {
+                return Err(Error::Domain(String::from("ZZZ is unreachable from AAA")));
+            }
+            let next_node_id = match instruction {
+                Instruction::Left => self.go_left_from(cur_node_id),
+                Instruction::Right => self.go_right_from(cur_node_id),
+            }
+            .ok_or_else(|| Error::Domain(format!("Node {} does not exist", cur_node_id)))?;
+            trace!(
+                Level::Trace,
+                "Step {}: {} from {} to {}",
+                num_steps + 1,
+                match instruction {
+                    Instruction::Left => "left",
+                    Instruction::Right => "right",
+                },
+                cur_node_id,
+                next_node_id
+            );
+            cur_node_id = next_node_id;
+        }
+        unreachable!("Cycling through instructions never ends")
+    }
+
+    /// Counts the steps until every walk from a node ending in `A` is on a
+    /// node ending in `Z` at once.
+    // Too slow to finish on the real input, so part 2 does not use it yet.
+    pub fn measure_ghost_walk(&self, instructions: &[Instruction]) -> Option<usize> {
+        let repeating_instructions = instructions.iter().cycle();
+        let mut cur_node_id = self
+            .nodes_by_id
+            .values()
+            .map(|node| node.id.clone())
+            .filter(|id| id.ends_with("A"))
+            .collect::<Vec<_>>();
+        for (num_steps, instruction) in repeating_instructions.enumerate() {
+            if cur_node_id
+                .iter()
+                .all(|id| id.ends_with("Z"))
+            {
+                return Some(num_steps);
+            }
+            cur_node_id = cur_node_id
+                .iter()
+                .map(|id| match instruction {
+                    Instruction::Left => self.go_left_from(id).unwrap(),
+                    Instruction::Right => self.go_right_from(id).unwrap(),
+                })
+                .cloned()
+                .collect::<_>();
+        }
+        // In case there are no elements to loop over
+        None
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<Instruction>, Network), ParseError> {
+    let input = Span::new(input);
+    let mut sections = input.sections();
+    let instruction_str = sections
+        .next()
+        .ok_or_else(|| input.error("Missing instructions"))?;
+    let instructions = instruction_str
+        .chars()
+        .map(|instruction| instruction.parse())
+        .collect::<Result<_, _>>()?;
+    let nodes = sections
+        .next()
+        .ok_or_else(|| input.error("Missing nodes"))?
+        .lines()
+        .map(|line| line.parse())
+        .collect::<Result<Vec<Node>, _>>()?;
+
+    Ok((instructions, Network::from(nodes.as_slice())))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = (Vec<Instruction>, Network);
+
+    fn parse(input: &str) -> common::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_one((instructions, network): &Self::Input) -> common::Result<Option<String>> {
+        let part_1_answer = network.measure_walk(instructions.as_slice())?;
+        Ok(Some(part_1_answer.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+    const EXAMPLE_2: &str = include_str!("../example-2.txt");
+    const EXAMPLE_3: &str = include_str!("../example-3.txt");
+
+    #[test]
+    fn walk_without_repeating_instructions() {
+        let (instructions, network) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(
+            network
+                .measure_walk(&instructions)
+                .unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn walk_repeating_instructions() {
+        let (instructions, network) = parse_input(EXAMPLE_2).unwrap();
+        assert_eq!(
+            network
+                .measure_walk(&instructions)
+                .unwrap(),
+            6
+        );
+    }
+
+    #[test]
+    fn ghost_walk() {
+        let (instructions, network) = parse_input(EXAMPLE_3).unwrap();
+        assert_eq!(network.measure_ghost_walk(&instructions), Some(6));
+    }
+
+    #[test]
+    fn invalid_instructions_are_errors() {
+        let error = parse_input("LRX\n\nAAA = (AAA, AAA)\n")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (Some(1), Some(3)));
+    }
+
+    #[test]
+    fn example_part_one() {
+        let input = Day08::parse(EXAMPLE_2).unwrap();
+        assert_eq!(Day08::part_one(&input).unwrap(), Some(String::from("6")));
+    }
+
+    #[test]
+    #[ignore = "the ghost walk is too slow on real inputs, so part two is not solved yet"]
+    fn example_part_two() {
+        let input = Day08::parse(EXAMPLE_3).unwrap();
+        assert_eq!(Day08::part_two(&input).unwrap(), Some(String::from("6")));
+    }
+}
@@ -0,0 +1,63 @@
+use common::{AnonymisingSolution, Error, Rng, Span};
+
+use crate::{parse_series, Day09};
+
+impl AnonymisingSolution for Day09 {
+    /// Adds a random line `a * i + b` to each series. Differences past the
+    /// first are unchanged, so every series still extrapolates, each by that
+    /// line's value either side of it.
+    fn anonymise(input: &str, rng: &mut Rng) -> common::Result<String> {
+        let mut output = String::new();
+        for line in Span::new(input).lines() {
+            let series = parse_series(line)?;
+            let (slope, offset) = (rng.between(-20..=20) as i32, rng.between(-20..=20) as i32);
+            let series = series
+                .iter()
+                .zip(0..)
+                .map(|(&reading, i): (&i32, i32)| {
+                    slope
+                        .checked_mul(i)
+                        .and_then(|shift| shift.checked_add(offset))
+                        .and_then(|shift| shift.checked_add(reading))
+                        .map(|reading| reading.to_string())
+                        .ok_or_else(|| {
+                            Error::Domain(format!("Line {}: readings overflow", line.location().0))
+                        })
+                })
+                .collect::<common::Result<Vec<_>>>()?;
+            output += &format!("{}\n", series.join(" "));
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+    use crate::extrapolate;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn series_shift_by_a_line() {
+        let series_arrays = Day09::parse(EXAMPLE).unwrap();
+        for seed in 0..10 {
+            let anonymised = Day09::anonymise(EXAMPLE, &mut Rng::new(seed)).unwrap();
+            let shifted = Day09::parse(&anonymised).unwrap();
+            for (series, shifted) in series_arrays.iter().zip(&shifted) {
+                let (slope, offset) = (
+                    i64::from(shifted[1] - series[1] - shifted[0] + series[0]),
+                    i64::from(shifted[0] - series[0]),
+                );
+                let (first, last) = extrapolate(series).unwrap();
+                let length = series.len() as i64;
+                assert_eq!(
+                    extrapolate(shifted).unwrap(),
+                    (first - slope + offset, last + slope * length + offset)
+                );
+            }
+        }
+    }
+}
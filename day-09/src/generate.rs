@@ -0,0 +1,66 @@
+use common::{Generated, GeneratingSolution, Rng};
+
+use crate::Day09;
+
+const READINGS: i64 = 21;
+
+const MAX_SERIES: usize = 1000;
+
+/// A polynomial of degree at most 4, lowest coefficient first.
+struct Polynomial([i64; 5]);
+
+impl Polynomial {
+    fn at(&self, x: i64) -> i64 {
+        self.0
+            .iter()
+            .rev()
+            .fold(0, |value, coefficient| value * x + coefficient)
+    }
+}
+
+impl GeneratingSolution for Day09 {
+    /// Generates `size` series, at most 1000, each reading a small polynomial
+    /// at 21 points. The extrapolated values are the polynomial either side.
+    fn generate(rng: &mut Rng, size: usize) -> Generated {
+        let mut input = String::new();
+        let mut next_sum = 0;
+        let mut previous_sum = 0;
+        for _ in 0..size.min(MAX_SERIES) {
+            let degree = rng.index(5);
+            let mut coefficients = [0; 5];
+            for coefficient in &mut coefficients[..=degree] {
+                *coefficient = rng.between(-5..=5);
+            }
+            let polynomial = Polynomial(coefficients);
+
+            let readings = (0..READINGS)
+                .map(|x| polynomial.at(x).to_string())
+                .collect::<Vec<_>>();
+            input += &readings.join(" ");
+            input.push('\n');
+            next_sum += polynomial.at(READINGS);
+            previous_sum += polynomial.at(-1);
+        }
+        Generated {
+            input,
+            answers: [Some(next_sum.to_string()), Some(previous_sum.to_string())],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+
+    #[test]
+    fn generated_answers_match() {
+        for seed in 0..20 {
+            let generated = Day09::generate(&mut Rng::new(seed), 100);
+            let input = Day09::parse(&generated.input).unwrap();
+            assert_eq!(Day09::part_one(&input).unwrap(), generated.answers[0]);
+            assert_eq!(Day09::part_two(&input).unwrap(), generated.answers[1]);
+        }
+    }
+}
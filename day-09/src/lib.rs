@@ -0,0 +1,223 @@
+//! Day 9: Mirage Maintenance
+
+mod anonymise;
+mod generate;
+
+use common::{trace, Error, Level, Line, ParseError, Solution, Span, StreamingSolution};
+
+/// The values before the first and after the last of `series`, failing if
+/// the series is empty or its differences grow past what an `i64` holds.
+pub fn extrapolate(series: &[i32]) -> common::Result<(i64, i64)> {
+    if series.is_empty() {
+        return Err(Error::Domain(String::from(
+            "Cannot extrapolate from empty series",
+        )));
+    }
+    let series = series
+        .iter()
+        .map(|&reading| i64::from(reading))
+        .collect::<Vec<_>>();
+    extrapolate_differences(&series)
+}
+
+fn extrapolate_differences(series: &[i64]) -> common::Result<(i64, i64)> {
+    trace!(Level::Trace, "{:?}", series);
+    let first = series[0];
+    let last = series[series.len() - 1];
+    if series.iter().all(|&item| item == first) {
+        return Ok((first, first));
+    }
+    let differences = series
+        .windows(2)
+        .map(|w| w[1].checked_sub(w[0]))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(overflow)?;
+    let (before, after) = extrapolate_differences(&differences)?;
+    Ok((
+        first
+            .checked_sub(before)
+            .ok_or_else(overflow)?,
+        last.checked_add(after)
+            .ok_or_else(overflow)?,
+    ))
+}
+
+/// Like [`extrapolate`], tracing what the series extends to.
+fn extrapolate_traced(series: &[i32]) -> common::Result<(i64, i64)> {
+    let (first, last) = extrapolate(series)?;
+    trace!(
+        Level::Debug,
+        "{:?} extends to {} before and {} after",
+        series,
+        first,
+        last
+    );
+    Ok((first, last))
+}
+
+/// Reads one line of readings.
+pub fn parse_series(line: Span<'_>) -> Result<Vec<i32>, ParseError> {
+    match line.numbers::<i32>()? {
+        series if series.is_empty() => Err(line.error("Empty series")),
+        series => Ok(series),
+    }
+}
+
+pub fn sum_extrapolations(series_arrays: &[Vec<i32>]) -> common::Result<(i64, i64)> {
+    series_arrays
+        .iter()
+        .map(|series| extrapolate_traced(series.as_slice()))
+        .reduce(|acc, extrapolation| {
+            let ((acc_first, acc_last), (first, last)) = (acc?, extrapolation?);
+            add_extrapolations((acc_first, acc_last), (first, last))
+        })
+        .ok_or_else(no_series)?
+}
+
+fn add_extrapolations(
+    (sum_first, sum_last): (i64, i64),
+    (first, last): (i64, i64),
+) -> common::Result<(i64, i64)> {
+    Ok((
+        sum_first
+            .checked_add(first)
+            .ok_or_else(overflow)?,
+        sum_last
+            .checked_add(last)
+            .ok_or_else(overflow)?,
+    ))
+}
+
+fn no_series() -> Error {
+    Error::Domain(String::from("No series to extrapolate"))
+}
+
+fn overflow() -> Error {
+    Error::Domain(String::from("Extrapolation overflows"))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> common::Result<Self::Input> {
+        let series_arrays = Span::new(input)
+            .lines()
+            .map(parse_series)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(series_arrays)
+    }
+
+    fn part_one(series_arrays: &Self::Input) -> common::Result<Option<String>> {
+        let answers = sum_extrapolations(series_arrays)?;
+        Ok(Some(answers.1.to_string()))
+    }
+
+    fn part_two(series_arrays: &Self::Input) -> common::Result<Option<String>> {
+        let answers = sum_extrapolations(series_arrays)?;
+        Ok(Some(answers.0.to_string()))
+    }
+}
+
+impl StreamingSolution for Day09 {
+    fn solve_lines(
+        lines: &mut dyn Iterator<Item = common::Result<Line>>,
+    ) -> common::Result<[Option<String>; 2]> {
+        let mut answers = None;
+        for line in lines {
+            let line = line?;
+            let series = parse_series(line.span()).map_err(|error| line.locate(error))?;
+            let extrapolation = extrapolate_traced(&series)?;
+            answers = Some(add_extrapolations(
+                answers.unwrap_or((0, 0)),
+                extrapolation,
+            )?);
+        }
+        let answers = answers.ok_or_else(no_series)?;
+        Ok([Some(answers.1.to_string()), Some(answers.0.to_string())])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Lines;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn extrapolate_each_series() {
+        let series_arrays = Day09::parse(EXAMPLE).unwrap();
+        let extrapolations = series_arrays
+            .iter()
+            .map(|series| extrapolate(series).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(extrapolations, [(-3, 18), (0, 28), (5, 68)]);
+    }
+
+    #[test]
+    fn extreme_readings_extrapolate() {
+        assert_eq!(
+            extrapolate(&[i32::MAX, i32::MIN]).unwrap(),
+            (3 * i64::from(i32::MAX) + 1, -3 * i64::from(i32::MAX) - 2)
+        );
+        let input = Day09::parse("2147483647 -2147483648\n").unwrap();
+        assert_eq!(
+            Day09::part_one(&input).unwrap(),
+            Some(String::from("-6442450943"))
+        );
+    }
+
+    #[test]
+    fn overflowing_differences_are_errors() {
+        let series = (0..70)
+            .map(|i| if i % 2 == 0 { i32::MAX } else { i32::MIN })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            extrapolate(&series)
+                .unwrap_err()
+                .to_string(),
+            "Extrapolation overflows"
+        );
+        assert!(extrapolate(&[]).is_err());
+    }
+
+    #[test]
+    fn example_part_one() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part_one(&input).unwrap(), Some(String::from("114")));
+    }
+
+    #[test]
+    fn example_part_two() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part_two(&input).unwrap(), Some(String::from("2")));
+    }
+
+    #[test]
+    fn streamed_example() {
+        let answers = Day09::solve_lines(&mut Lines::new(EXAMPLE.as_bytes(), "example")).unwrap();
+        assert_eq!(
+            answers,
+            [Some(String::from("114")), Some(String::from("2"))]
+        );
+    }
+
+    #[test]
+    fn streamed_sums_pass_i32() {
+        let input = "2147483647\n".repeat(3);
+        let answers = Day09::solve_lines(&mut Lines::new(input.as_bytes(), "test")).unwrap();
+        let sum = Some(String::from("6442450941"));
+        assert_eq!(answers, [sum.clone(), sum]);
+    }
+
+    #[test]
+    fn overflowing_sums_are_errors() {
+        assert!(add_extrapolations((i64::MAX, 0), (1, 0)).is_err());
+        assert!(add_extrapolations((0, i64::MIN), (0, -1)).is_err());
+    }
+}
@@ -0,0 +1,9 @@
+#![no_main]
+
+use common::Solution;
+use day_09::Day09;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day09::parse(input);
+});
@@ -0,0 +1,5 @@
+fn main() {
+    // The tests embed Python, and need to find libpython wherever it is
+    // installed
+    pyo3_build_config::add_libpython_rpath_link_args();
+}
@@ -0,0 +1,127 @@
+//! Day 2: Cube Conundrum
+
+use day_02::REAL_AMOUNTS;
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use crate::to_py_err;
+
+/// A handful of cubes, or a bag of them.
+#[pyclass(module = "aoc", frozen, eq)]
+#[derive(PartialEq)]
+pub struct CubeSet(day_02::CubeSet);
+
+#[pymethods]
+impl CubeSet {
+    #[new]
+    #[pyo3(signature = (red = 0, green = 0, blue = 0))]
+    fn new(red: u32, green: u32, blue: u32) -> Self {
+        CubeSet(day_02::CubeSet { red, green, blue })
+    }
+
+    #[getter]
+    fn red(&self) -> u32 {
+        self.0.red
+    }
+
+    #[getter]
+    fn green(&self) -> u32 {
+        self.0.green
+    }
+
+    #[getter]
+    fn blue(&self) -> u32 {
+        self.0.blue
+    }
+
+    fn power(&self) -> PyResult<u32> {
+        self.0
+            .power()
+            .map_err(|error| PyValueError::new_err(error.to_string()))
+    }
+
+    /// Whether the cubes could be drawn from `bag`, by default the bag of
+    /// part 1.
+    #[pyo3(signature = (bag = None))]
+    fn is_possible(&self, bag: Option<&Self>) -> bool {
+        self.0
+            .is_possible(bag.map_or(&REAL_AMOUNTS, |bag| &bag.0))
+    }
+
+    /// The most cubes of each colour in either set.
+    fn maximum(&self, other: &Self) -> Self {
+        CubeSet(self.0.maximum(&other.0))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "CubeSet(red={}, green={}, blue={})",
+            self.0.red, self.0.green, self.0.blue
+        )
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+#[pyclass(module = "aoc", frozen)]
+pub struct Game(day_02::Game);
+
+#[pymethods]
+impl Game {
+    /// Reads one line of the puzzle input.
+    #[staticmethod]
+    fn parse(line: &str) -> PyResult<Self> {
+        line.parse()
+            .map(Game)
+            .map_err(to_py_err)
+    }
+
+    #[getter]
+    fn id(&self) -> u32 {
+        self.0.id
+    }
+
+    #[getter]
+    fn rounds(&self) -> Vec<CubeSet> {
+        self.0
+            .rounds
+            .iter()
+            .copied()
+            .map(CubeSet)
+            .collect()
+    }
+
+    /// Whether every round could be drawn from `bag`, by default the bag of
+    /// part 1.
+    #[pyo3(signature = (bag = None))]
+    fn is_possible(&self, bag: Option<&CubeSet>) -> bool {
+        self.0
+            .is_possible(bag.map_or(&REAL_AMOUNTS, |bag| &bag.0))
+    }
+
+    /// The fewest cubes of each colour which make every round possible.
+    fn minimum_set(&self) -> CubeSet {
+        CubeSet(self.0.get_minimum_set())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Game {} of {} rounds>", self.0.id, self.0.rounds.len())
+    }
+}
+
+/// Reads every game of the puzzle input.
+#[pyfunction]
+fn parse_games(input: &str) -> PyResult<Vec<Game>> {
+    common::parse_lines(input)
+        .map(|games| games.into_iter().map(Game).collect())
+        .map_err(to_py_err)
+}
+
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<CubeSet>()?;
+    m.add_class::<Game>()?;
+    m.add_function(wrap_pyfunction!(parse_games, m)?)?;
+    m.add("REAL_AMOUNTS", CubeSet(REAL_AMOUNTS))?;
+    Ok(())
+}
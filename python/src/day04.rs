@@ -0,0 +1,97 @@
+//! Day 4: Scratchcards
+
+use std::collections::HashSet;
+
+use day_04::ScratchCardCounter;
+use pyo3::prelude::*;
+
+use crate::to_py_err;
+
+#[pyclass(module = "aoc", frozen)]
+pub struct ScratchCard(day_04::ScratchCard);
+
+#[pymethods]
+impl ScratchCard {
+    #[new]
+    fn new(winning_numbers: HashSet<u8>, numbers: Vec<u8>) -> Self {
+        ScratchCard(day_04::ScratchCard {
+            winning_numbers,
+            numbers,
+        })
+    }
+
+    /// Reads one line of the puzzle input.
+    #[staticmethod]
+    fn parse(line: &str) -> PyResult<Self> {
+        line.parse()
+            .map(ScratchCard)
+            .map_err(to_py_err)
+    }
+
+    #[getter]
+    fn winning_numbers(&self) -> HashSet<u8> {
+        self.0.winning_numbers.clone()
+    }
+
+    // A `Vec<u8>` would come out as `bytes`
+    #[getter]
+    fn numbers(&self) -> Vec<u32> {
+        self.0
+            .numbers
+            .iter()
+            .copied()
+            .map(u32::from)
+            .collect()
+    }
+
+    fn winning_number_count(&self) -> usize {
+        self.0.get_winning_number_count()
+    }
+
+    fn points(&self) -> PyResult<u64> {
+        self.0.get_points().map_err(to_py_err)
+    }
+
+    fn __repr__(&self) -> String {
+        let mut winning_numbers = self
+            .0
+            .winning_numbers
+            .iter()
+            .collect::<Vec<_>>();
+        winning_numbers.sort();
+        format!("ScratchCard({:?}, {:?})", winning_numbers, self.0.numbers)
+    }
+}
+
+/// Reads every card of the puzzle input.
+#[pyfunction]
+fn parse_scratch_cards(input: &str) -> PyResult<Vec<ScratchCard>> {
+    common::parse_lines(input)
+        .map(|cards| {
+            cards
+                .into_iter()
+                .map(ScratchCard)
+                .collect()
+        })
+        .map_err(to_py_err)
+}
+
+/// Counts the cards held once every win has been paid out in copies of the
+/// cards that follow.
+#[pyfunction]
+fn count_scratch_cards(cards: Vec<PyRef<'_, ScratchCard>>) -> PyResult<u128> {
+    let mut counter = ScratchCardCounter::default();
+    for card in &cards {
+        counter
+            .add(&card.0)
+            .map_err(to_py_err)?;
+    }
+    counter.card_count().map_err(to_py_err)
+}
+
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<ScratchCard>()?;
+    m.add_function(wrap_pyfunction!(parse_scratch_cards, m)?)?;
+    m.add_function(wrap_pyfunction!(count_scratch_cards, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,120 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+use common::Solution;
+use day_05::{feed_forward, Day05, RangeQueryResult};
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use crate::to_py_err;
+
+/// One line of an almanac map.
+#[pyclass(module = "aoc", frozen)]
+pub struct MappingRange(day_05::MappingRange);
+
+#[pymethods]
+impl MappingRange {
+    #[new]
+    fn new(destination_start: u64, source_start: u64, length: u64) -> PyResult<Self> {
+        // Parsing holds the checks on empty and overflowing ranges
+        Self::parse(&format!(
+            "{} {} {}",
+            destination_start, source_start, length
+        ))
+    }
+
+    /// Reads one line of an almanac map.
+    #[staticmethod]
+    fn parse(line: &str) -> PyResult<Self> {
+        line.parse()
+            .map(MappingRange)
+            .map_err(to_py_err)
+    }
+
+    #[getter]
+    fn destination_start(&self) -> u64 {
+        self.0.destination_start
+    }
+
+    #[getter]
+    fn source_start(&self) -> u64 {
+        self.0.source_start
+    }
+
+    #[getter]
+    fn length(&self) -> u64 {
+        self.0.length
+    }
+
+    fn __contains__(&self, point: u64) -> bool {
+        self.0.query(point) == RangeQueryResult::Contains
+    }
+
+    /// Maps a point inside the range.
+    fn map(&self, point: u64) -> PyResult<u64> {
+        if self.__contains__(point) {
+            Ok(self.0.map(point))
+        } else {
+            Err(PyValueError::new_err(format!(
+                "{} falls outside of {}",
+                point,
+                self.__repr__()
+            )))
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "MappingRange({}, {}, {})",
+            self.0.destination_start, self.0.source_start, self.0.length
+        )
+    }
+}
+
+#[pyclass(module = "aoc", frozen)]
+pub struct Almanac(day_05::Almanac);
+
+#[pymethods]
+impl Almanac {
+    /// Reads the whole puzzle input.
+    #[staticmethod]
+    fn parse(input: &str) -> PyResult<Self> {
+        Day05::parse(input)
+            .map(Almanac)
+            .map_err(to_py_err)
+    }
+
+    #[getter]
+    fn seeds(&self) -> Vec<u64> {
+        self.0.seed_numbers.clone()
+    }
+
+    /// Where `seed` is planted.
+    fn locate(&self, seed: u64) -> u64 {
+        feed_forward(&self.0.maps, seed)
+    }
+
+    /// What `seed` maps to through each map in turn, starting with the seed
+    /// itself.
+    fn path(&self, seed: u64) -> Vec<u64> {
+        let mut path = vec![seed];
+        for map in &self.0.maps {
+            let last = path[path.len() - 1];
+            path.push(map.map(last).unwrap_or(last));
+        }
+        path
+    }
+
+    /// The lowest location any seed is planted at, or `None` without seeds.
+    fn lowest_location(&self) -> Option<u64> {
+        self.0
+            .seed_numbers
+            .iter()
+            .map(|&seed| self.locate(seed))
+            .min()
+    }
+}
+
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<MappingRange>()?;
+    m.add_class::<Almanac>()?;
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! Day 6: Wait For It
+
+use day_06::{get_race_part_2, get_races};
+use pyo3::prelude::*;
+
+use crate::to_py_err;
+
+#[pyclass(module = "aoc", frozen)]
+pub struct Race(day_06::Race);
+
+#[pymethods]
+impl Race {
+    #[new]
+    fn new(time_limit: u64, record_distance: u64) -> Self {
+        Race(day_06::Race {
+            time_limit,
+            record_distance,
+        })
+    }
+
+    #[getter]
+    fn time_limit(&self) -> u64 {
+        self.0.time_limit
+    }
+
+    #[getter]
+    fn record_distance(&self) -> u64 {
+        self.0.record_distance
+    }
+
+    /// The hold times which exactly match the record, or `None` if no hold
+    /// time reaches it.
+    fn record_hold_times(&self) -> Option<(f64, f64)> {
+        let (low, high) = self.0.get_record_button_hold_time();
+        (!low.is_nan()).then_some((low, high))
+    }
+
+    fn ways_to_beat_record(&self) -> u64 {
+        self.0.count_ways_to_beat_record()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Race({}, {})", self.0.time_limit, self.0.record_distance)
+    }
+}
+
+/// Reads the races of part 1, one per column.
+#[pyfunction]
+fn parse_races(input: &str) -> PyResult<Vec<Race>> {
+    get_races(input)
+        .map(|races| races.into_iter().map(Race).collect())
+        .map_err(to_py_err)
+}
+
+/// Reads the single race of part 2, ignoring the spaces between digits.
+#[pyfunction]
+fn parse_kerned_race(input: &str) -> PyResult<Race> {
+    get_race_part_2(input)
+        .map(Race)
+        .map_err(to_py_err)
+}
+
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Race>()?;
+    m.add_function(wrap_pyfunction!(parse_races, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_kerned_race, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,139 @@
+//! Day 7: Camel Cards
+
+use day_07::{get_total_bid, Part1Card, Part2Card};
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*, types::PyBool};
+
+use crate::to_py_err;
+
+/// A hand read with or without jokers, which rank differently.
+#[derive(PartialEq, Eq)]
+enum Kind {
+    Plain(day_07::Hand<5, Part1Card>),
+    Jokers(day_07::Hand<5, Part2Card>),
+}
+
+#[pyclass(module = "aoc", frozen)]
+pub struct Hand(Kind);
+
+#[pymethods]
+impl Hand {
+    /// Reads one line of the puzzle input, `J` being a jack or, with
+    /// `jokers`, a joker.
+    #[staticmethod]
+    #[pyo3(signature = (line, jokers = false))]
+    fn parse(line: &str, jokers: bool) -> PyResult<Self> {
+        let kind = if jokers {
+            line.parse().map(Kind::Jokers)
+        } else {
+            line.parse().map(Kind::Plain)
+        };
+        kind.map(Hand).map_err(to_py_err)
+    }
+
+    #[getter]
+    fn cards(&self) -> String {
+        self.__str__()
+    }
+
+    #[getter]
+    fn bid(&self) -> u16 {
+        match &self.0 {
+            Kind::Plain(hand) => hand.bid(),
+            Kind::Jokers(hand) => hand.bid(),
+        }
+    }
+
+    /// What the hand counts as, such as `"one pair"`.
+    #[getter]
+    fn hand_type(&self) -> String {
+        match &self.0 {
+            Kind::Plain(hand) => hand.hand_type().to_string(),
+            Kind::Jokers(hand) => hand.hand_type().to_string(),
+        }
+    }
+
+    #[getter]
+    fn jokers(&self) -> bool {
+        matches!(self.0, Kind::Jokers(_))
+    }
+
+    /// Orders hands by strength. Hands with and without jokers do not
+    /// compare.
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
+        let ordering = match (&self.0, &other.0) {
+            (Kind::Plain(a), Kind::Plain(b)) => a.cmp(b),
+            (Kind::Jokers(a), Kind::Jokers(b)) => a.cmp(b),
+            _ => return py.NotImplemented(),
+        };
+        PyBool::new(py, op.matches(ordering))
+            .to_owned()
+            .into_any()
+            .unbind()
+    }
+
+    fn __str__(&self) -> String {
+        match &self.0 {
+            Kind::Plain(hand) => hand.to_string(),
+            Kind::Jokers(hand) => hand.to_string(),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Hand.parse('{} {}', jokers={})",
+            self.__str__(),
+            self.bid(),
+            if self.jokers() { "True" } else { "False" }
+        )
+    }
+}
+
+/// Reads every hand of the puzzle input.
+#[pyfunction]
+#[pyo3(signature = (input, jokers = false))]
+fn parse_hands(input: &str, jokers: bool) -> PyResult<Vec<Hand>> {
+    let hands = if jokers {
+        common::parse_lines(input).map(|hands| {
+            hands
+                .into_iter()
+                .map(Kind::Jokers)
+                .collect()
+        })
+    } else {
+        common::parse_lines(input).map(|hands| {
+            hands
+                .into_iter()
+                .map(Kind::Plain)
+                .collect()
+        })
+    };
+    hands
+        .map(|hands: Vec<_>| hands.into_iter().map(Hand).collect())
+        .map_err(to_py_err)
+}
+
+/// Sums each hand's bid multiplied by its rank, the weakest hand ranking 1.
+#[pyfunction]
+fn total_bid(hands: Vec<PyRef<'_, Hand>>) -> PyResult<u128> {
+    let mut plain = vec![];
+    let mut jokers = vec![];
+    for hand in &hands {
+        match &hand.0 {
+            Kind::Plain(hand) => plain.push(hand.clone()),
+            Kind::Jokers(hand) => jokers.push(hand.clone()),
+        }
+    }
+    if !plain.is_empty() && !jokers.is_empty() {
+        return Err(PyValueError::new_err(
+            "Hands with and without jokers do not rank together",
+        ));
+    }
+    Ok(get_total_bid(&plain) + get_total_bid(&jokers))
+}
+
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Hand>()?;
+    m.add_function(wrap_pyfunction!(parse_hands, m)?)?;
+    m.add_function(wrap_pyfunction!(total_bid, m)?)?;
+    Ok(())
+}
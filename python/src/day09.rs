@@ -0,0 +1,25 @@
+//! Day 9: Mirage Maintenance
+
+use common::Solution;
+use day_09::Day09;
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use crate::to_py_err;
+
+/// The values before the first and after the last of `series`.
+#[pyfunction]
+fn extrapolate(series: Vec<i32>) -> PyResult<(i64, i64)> {
+    day_09::extrapolate(&series).map_err(|error| PyValueError::new_err(error.to_string()))
+}
+
+/// Reads every series of the puzzle input.
+#[pyfunction]
+fn parse_series(input: &str) -> PyResult<Vec<Vec<i32>>> {
+    Day09::parse(input).map_err(to_py_err)
+}
+
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(extrapolate, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_series, m)?)?;
+    Ok(())
+}
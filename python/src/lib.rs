@@ -0,0 +1,112 @@
+//! Python bindings for the day solvers and their domain types, as a module
+//! named `aoc`.
+//!
+//! Build the extension with
+//!
+//! ```sh
+//! PYO3_BUILD_EXTENSION_MODULE=1 cargo build --release -p aoc-python
+//! cp target/release/libaoc_python.so aoc.so
+//! ```
+//!
+//! after which `import aoc` works from the directory holding `aoc.so`. The
+//! tests in `tests/` run against an interpreter embedded in `cargo test`, so
+//! they need no build step of their own.
+
+mod day02;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day09;
+
+use common::{Part, DEFAULT_YEAR};
+use pyo3::{
+    exceptions::{PyRuntimeError, PyValueError},
+    prelude::*,
+};
+
+/// Raises unreadable input as `ValueError`, and anything else as
+/// `RuntimeError`.
+fn to_py_err(error: impl Into<common::Error>) -> PyErr {
+    match error.into() {
+        error @ common::Error::Parse(_) => PyValueError::new_err(error.to_string()),
+        error => PyRuntimeError::new_err(error.to_string()),
+    }
+}
+
+/// The answers to both parts of `day` for `input`, `None` where a part is
+/// unsolved.
+#[pyfunction]
+#[pyo3(signature = (day, input, year = DEFAULT_YEAR))]
+fn solve(day: u8, input: &str, year: u16) -> PyResult<(Option<String>, Option<String>)> {
+    let day = registry::find(year, day)
+        .ok_or_else(|| PyValueError::new_err(format!("No solution for day {} of {}", day, year)))?;
+    let mut answers = day
+        .run(input, &Part::ALL)
+        .map_err(to_py_err)?
+        .parts
+        .into_iter()
+        .map(|outcome| outcome.answer.map_err(to_py_err));
+    Ok((answers.next().unwrap()?, answers.next().unwrap()?))
+}
+
+/// The days with a solution, as `(year, day)` pairs.
+#[pyfunction(name = "days")]
+fn solved_days() -> Vec<(u16, u8)> {
+    registry::days()
+        .iter()
+        .map(|day| (day.year, day.number))
+        .collect()
+}
+
+#[pymodule]
+fn aoc(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(solved_days, m)?)?;
+    m.add("DEFAULT_YEAR", DEFAULT_YEAR)?;
+    day02::register(m)?;
+    day04::register(m)?;
+    day05::register(m)?;
+    day06::register(m)?;
+    day07::register(m)?;
+    day09::register(m)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use pyo3::types::IntoPyDict;
+
+    use super::*;
+
+    /// Runs the Python tests against the module, in an embedded interpreter.
+    #[test]
+    fn python_tests_pass() {
+        pyo3::append_to_inittab!(aoc);
+        Python::initialize();
+        let tests = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+        Python::attach(|py| -> PyResult<()> {
+            let unittest = py.import("unittest")?;
+            let suite = unittest
+                .getattr("defaultTestLoader")?
+                .call_method1("discover", (tests,))?;
+            let result = unittest
+                .call_method(
+                    "TextTestRunner",
+                    (),
+                    Some(&[("verbosity", 2)].into_py_dict(py)?),
+                )?
+                .call_method1("run", (suite,))?;
+            assert!(
+                result
+                    .call_method0("wasSuccessful")?
+                    .extract::<bool>()?,
+                "Python tests failed"
+            );
+            Ok(())
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,216 @@
+"""Tests of the `aoc` module against the puzzle examples."""
+
+import unittest
+from pathlib import Path
+
+import aoc
+
+ROOT = Path(__file__).parents[2]
+
+
+def example(day, name="example.txt"):
+    return (ROOT / f"day-{day:02}" / name).read_text()
+
+
+class SolveTest(unittest.TestCase):
+    def test_examples(self):
+        self.assertEqual(aoc.solve(2, example(2)), ("8", "2286"))
+        self.assertEqual(aoc.solve(6, example(6)), ("288", "71503"))
+        self.assertEqual(aoc.solve(9, example(9)), ("114", "2"))
+
+    def test_unsolved_parts_are_none(self):
+        self.assertEqual(aoc.solve(5, example(5)), ("35", None))
+
+    def test_days(self):
+        self.assertEqual(aoc.days(), [(aoc.DEFAULT_YEAR, day) for day in range(1, 10)])
+
+    def test_unknown_days_are_errors(self):
+        with self.assertRaisesRegex(ValueError, "No solution for day 10 of 2023"):
+            aoc.solve(10, "")
+        with self.assertRaisesRegex(ValueError, "No solution for day 1 of 2015"):
+            aoc.solve(1, "", year=2015)
+
+    def test_parse_errors_are_value_errors(self):
+        with self.assertRaisesRegex(ValueError, "Parse error at 1:"):
+            aoc.solve(2, "Game one: 3 blue\n")
+
+    def test_domain_errors_are_runtime_errors(self):
+        with self.assertRaisesRegex(RuntimeError, "past the end of the table"):
+            aoc.solve(4, "Card 1: 1 | 1\n")
+
+
+class CubeSetTest(unittest.TestCase):
+    def test_power(self):
+        self.assertEqual(aoc.CubeSet(red=4, green=2, blue=6).power(), 48)
+        with self.assertRaisesRegex(ValueError, "overflows"):
+            aoc.CubeSet(100000, 100000, 100000).power()
+
+    def test_possible_with_the_bag_of_part_1(self):
+        self.assertTrue(aoc.CubeSet(12, 13, 14).is_possible())
+        self.assertFalse(aoc.CubeSet(red=13).is_possible())
+        self.assertTrue(aoc.CubeSet(red=13).is_possible(aoc.CubeSet(20, 0, 0)))
+
+    def test_maximum(self):
+        self.assertEqual(
+            aoc.CubeSet(1, 5, 2).maximum(aoc.CubeSet(3, 0, 2)), aoc.CubeSet(3, 5, 2)
+        )
+
+    def test_formatting(self):
+        cubes = aoc.CubeSet(1, 2, 3)
+        self.assertEqual(repr(cubes), "CubeSet(red=1, green=2, blue=3)")
+        self.assertEqual(str(cubes), "1 red, 2 green, 3 blue")
+        self.assertEqual(repr(aoc.REAL_AMOUNTS), "CubeSet(red=12, green=13, blue=14)")
+
+
+class GameTest(unittest.TestCase):
+    def test_parse(self):
+        game = aoc.Game.parse("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red")
+        self.assertEqual(game.id, 3)
+        self.assertEqual(game.rounds, [aoc.CubeSet(20, 8, 6), aoc.CubeSet(4, 0, 5)])
+        self.assertFalse(game.is_possible())
+        self.assertEqual(game.minimum_set(), aoc.CubeSet(20, 8, 6))
+
+    def test_parse_games(self):
+        games = aoc.parse_games(example(2))
+        self.assertEqual([game.id for game in games if game.is_possible()], [1, 2, 5])
+        self.assertEqual(sum(game.minimum_set().power() for game in games), 2286)
+
+    def test_parse_errors_are_located(self):
+        with self.assertRaisesRegex(ValueError, "Parse error at 2:"):
+            aoc.parse_games("Game 1: 1 red\nGame 2: 1 purple\n")
+
+
+class ScratchCardTest(unittest.TestCase):
+    def test_points(self):
+        card = aoc.ScratchCard.parse("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53")
+        self.assertEqual(card.winning_numbers, {41, 48, 83, 86, 17})
+        self.assertEqual(card.numbers, [83, 86, 6, 31, 17, 9, 48, 53])
+        self.assertEqual(card.winning_number_count(), 4)
+        self.assertEqual(card.points(), 8)
+
+    def test_constructor(self):
+        card = aoc.ScratchCard({1, 2}, [2, 3])
+        self.assertEqual(card.points(), 1)
+        self.assertEqual(repr(card), "ScratchCard([1, 2], [2, 3])")
+
+    def test_count_scratch_cards(self):
+        cards = aoc.parse_scratch_cards(example(4))
+        self.assertEqual(sum(card.points() for card in cards), 13)
+        self.assertEqual(aoc.count_scratch_cards(cards), 30)
+
+    def test_copies_past_the_end_of_the_table(self):
+        with self.assertRaisesRegex(RuntimeError, "past the end of the table"):
+            aoc.count_scratch_cards([aoc.ScratchCard({1}, [1])])
+
+
+class MappingRangeTest(unittest.TestCase):
+    def test_map(self):
+        mapping = aoc.MappingRange(52, 50, 48)
+        self.assertEqual(
+            (mapping.destination_start, mapping.source_start, mapping.length),
+            (52, 50, 48),
+        )
+        self.assertIn(79, mapping)
+        self.assertNotIn(98, mapping)
+        self.assertEqual(mapping.map(79), 81)
+        with self.assertRaisesRegex(ValueError, "98 falls outside of MappingRange"):
+            mapping.map(98)
+
+    def test_invalid_ranges(self):
+        with self.assertRaisesRegex(ValueError, "at least one number"):
+            aoc.MappingRange(1, 2, 0)
+        with self.assertRaisesRegex(ValueError, "past the largest number"):
+            aoc.MappingRange(0, 2**64 - 1, 2)
+        with self.assertRaisesRegex(ValueError, "exactly 3 numbers"):
+            aoc.MappingRange.parse("1 2")
+
+
+class AlmanacTest(unittest.TestCase):
+    def setUp(self):
+        self.almanac = aoc.Almanac.parse(example(5))
+
+    def test_locate(self):
+        self.assertEqual(self.almanac.seeds, [79, 14, 55, 13])
+        self.assertEqual(
+            [self.almanac.locate(seed) for seed in self.almanac.seeds], [82, 43, 86, 35]
+        )
+        self.assertEqual(self.almanac.lowest_location(), 35)
+
+    def test_path(self):
+        self.assertEqual(self.almanac.path(79), [79, 81, 81, 81, 74, 78, 78, 82])
+
+
+class RaceTest(unittest.TestCase):
+    def test_ways_to_beat_record(self):
+        races = aoc.parse_races(example(6))
+        self.assertEqual([race.ways_to_beat_record() for race in races], [4, 8, 9])
+        race = aoc.parse_kerned_race(example(6))
+        self.assertEqual((race.time_limit, race.record_distance), (71530, 940200))
+        self.assertEqual(race.ways_to_beat_record(), 71503)
+
+    def test_record_hold_times(self):
+        low, high = aoc.Race(7, 9).record_hold_times()
+        self.assertAlmostEqual(low, 1.697, places=3)
+        self.assertAlmostEqual(high, 5.303, places=3)
+        self.assertEqual(aoc.Race(2, 1).record_hold_times(), (1.0, 1.0))
+
+    def test_unbeatable_records(self):
+        race = aoc.Race(3, 5)
+        self.assertIsNone(race.record_hold_times())
+        self.assertEqual(race.ways_to_beat_record(), 0)
+
+    def test_long_races(self):
+        race = aoc.Race(2**64 - 1, 2**64 - 1)
+        self.assertEqual(race.ways_to_beat_record(), 2**64 - 4)
+        low, high = race.record_hold_times()
+        self.assertAlmostEqual(high / 2**64, 1.0)
+
+
+class HandTest(unittest.TestCase):
+    def test_parse(self):
+        hand = aoc.Hand.parse("T55J5 684")
+        self.assertEqual((hand.cards, hand.bid), ("T55J5", 684))
+        self.assertEqual(hand.hand_type, "three of a kind")
+        self.assertFalse(hand.jokers)
+        self.assertEqual(aoc.Hand.parse("T55J5 684", jokers=True).hand_type, "four of a kind")
+        self.assertEqual(repr(hand), "Hand.parse('T55J5 684', jokers=False)")
+
+    def test_ordering(self):
+        hands = aoc.parse_hands(example(7))
+        self.assertEqual(
+            [hand.cards for hand in sorted(hands)],
+            ["32T3K", "KTJJT", "KK677", "T55J5", "QQQJA"],
+        )
+        hands = aoc.parse_hands(example(7), jokers=True)
+        self.assertEqual(
+            [hand.cards for hand in sorted(hands)],
+            ["32T3K", "KK677", "T55J5", "QQQJA", "KTJJT"],
+        )
+
+    def test_hands_with_and_without_jokers_do_not_compare(self):
+        with self.assertRaises(TypeError):
+            aoc.Hand.parse("32T3K 1") < aoc.Hand.parse("32T3K 1", jokers=True)
+        self.assertNotEqual(aoc.Hand.parse("32T3K 1"), aoc.Hand.parse("32T3K 1", jokers=True))
+
+    def test_total_bid(self):
+        self.assertEqual(aoc.total_bid(aoc.parse_hands(example(7))), 6440)
+        self.assertEqual(aoc.total_bid(aoc.parse_hands(example(7), jokers=True)), 5905)
+        self.assertEqual(aoc.total_bid([]), 0)
+        with self.assertRaisesRegex(ValueError, "do not rank together"):
+            aoc.total_bid([aoc.Hand.parse("32T3K 1"), aoc.Hand.parse("32T3K 1", jokers=True)])
+
+
+class ExtrapolateTest(unittest.TestCase):
+    def test_extrapolate(self):
+        self.assertEqual(aoc.extrapolate([10, 13, 16, 21, 30, 45]), (5, 68))
+        with self.assertRaisesRegex(ValueError, "empty series"):
+            aoc.extrapolate([])
+
+    def test_parse_series(self):
+        series = aoc.parse_series(example(9))
+        self.assertEqual(series[0], [0, 3, 6, 9, 12, 15])
+        self.assertEqual(sum(aoc.extrapolate(values)[1] for values in series), 114)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,62 @@
+//! Every day with a solution, shared by the runner and the Python bindings.
+//! `aoc new` adds each new day here.
+
+use common::Day;
+use day_01::Day01;
+use day_02::Day02;
+use day_03::Day03;
+use day_04::Day04;
+use day_05::Day05;
+use day_06::Day06;
+use day_07::Day07;
+use day_08::Day08;
+use day_09::Day09;
+
+/// Every day with a solution, each year's in order.
+pub fn days() -> Vec<Day> {
+    vec![
+        Day::of::<Day01>()
+            .with_streaming::<Day01>()
+            .with_generator::<Day01>(),
+        Day::of::<Day02>()
+            .with_generator::<Day02>()
+            .with_explorer::<Day02>(),
+        Day::of::<Day03>().with_generator::<Day03>(),
+        Day::of::<Day04>()
+            .with_streaming::<Day04>()
+            .with_generator::<Day04>()
+            .with_explorer::<Day04>()
+            .with_anonymiser::<Day04>(),
+        Day::of::<Day05>()
+            .with_generator::<Day05>()
+            .with_explorer::<Day05>()
+            .with_anonymiser::<Day05>(),
+        Day::of::<Day06>().with_generator::<Day06>(),
+        Day::of::<Day07>()
+            .with_streaming::<Day07>()
+            .with_generator::<Day07>()
+            .with_anonymiser::<Day07>(),
+        Day::of::<Day08>()
+            .with_generator::<Day08>()
+            .with_explorer::<Day08>()
+            .with_anonymiser::<Day08>(),
+        Day::of::<Day09>()
+            .with_streaming::<Day09>()
+            .with_generator::<Day09>()
+            .with_anonymiser::<Day09>(),
+    ]
+}
+
+/// The days of `year` with a solution, in order.
+pub fn days_of(year: u16) -> Vec<Day> {
+    days()
+        .into_iter()
+        .filter(|day| day.year == year)
+        .collect()
+}
+
+pub fn find(year: u16, number: u8) -> Option<Day> {
+    days()
+        .into_iter()
+        .find(|day| day.year == year && day.number == number)
+}